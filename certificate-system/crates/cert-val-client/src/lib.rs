@@ -0,0 +1,1589 @@
+//! Rust client for the certificate system and institute validator programs
+//!
+//! Derives every program PDA, builds instructions from the programs' own Anchor
+//! account and instruction types, fetches and decodes accounts over RPC, and maps
+//! program error codes back to `CertificateError` / `ValidatorError`.
+
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{
+    AccountDeserialize, AnchorDeserialize, Discriminator, InstructionData, ToAccountMetas,
+};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::{Error as RpcError, ErrorKind as RpcErrorKind};
+use solana_rpc_client_api::config::{
+    RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSimulateTransactionConfig,
+};
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+use solana_rpc_client_api::request::{RpcError as RpcRequestError, RpcResponseErrorData};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signature::{Signature, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use solana_system_interface::instruction as system_instruction;
+use std::collections::VecDeque;
+
+pub use certificate_system::{
+    CertificateError, CertificateFilter, CertificateHolder, CredentialSchemaParams,
+    RevocationReason,
+};
+pub use institute_validator::{GovernanceParams, InstituteProfileParams, ValidatorError};
+pub use {certificate_system, institute_validator};
+
+/// Program ID of the certificate system
+pub const CERTIFICATE_SYSTEM_ID: Pubkey = certificate_system::ID;
+/// Program ID of the institute validator
+pub const INSTITUTE_VALIDATOR_ID: Pubkey = institute_validator::ID;
+
+// ============================================================================
+// PDAs
+// ============================================================================
+
+/// Certificate PDA of a certificate hash
+pub fn find_certificate_pda(certificate_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"certificate", certificate_hash], &CERTIFICATE_SYSTEM_ID)
+}
+
+/// CertificateRenewal PDA of the `sequence`-th renewal of a certificate
+pub fn find_certificate_renewal_pda(certificate_hash: &[u8; 32], sequence: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"certificate_renewal", certificate_hash, &sequence.to_le_bytes()],
+        &CERTIFICATE_SYSTEM_ID,
+    )
+}
+
+/// IssuerStats PDA of an institute
+pub fn find_issuer_stats_pda(institute: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"issuer_stats", institute.as_ref()], &CERTIFICATE_SYSTEM_ID)
+}
+
+/// CertificateIndex PDA of an institute's `serial`-th certificate
+pub fn find_certificate_index_pda(institute: &Pubkey, serial: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"cert_by_issuer", institute.as_ref(), &serial.to_le_bytes()],
+        &CERTIFICATE_SYSTEM_ID,
+    )
+}
+
+/// CertificateFilterState PDA, pointing at the current CertificateFilter generation
+pub fn find_certificate_filter_state_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"certificate_filter_state"], &CERTIFICATE_SYSTEM_ID)
+}
+
+/// CertificateBatch PDA of a Merkle root anchored by `institute`
+pub fn find_certificate_batch_pda(institute: &Pubkey, merkle_root: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"certificate_batch", institute.as_ref(), merkle_root],
+        &CERTIFICATE_SYSTEM_ID,
+    )
+}
+
+/// BatchLeafRevocation PDA of a leaf in a batch
+pub fn find_batch_revocation_pda(batch: &Pubkey, leaf_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"batch_revocation", batch.as_ref(), &leaf_index.to_le_bytes()],
+        &CERTIFICATE_SYSTEM_ID,
+    )
+}
+
+/// CredentialSchema PDA of an institute's schema name and version
+pub fn find_credential_schema_pda(institute: &Pubkey, name: &str, version: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"credential_schema",
+            institute.as_ref(),
+            name.as_bytes(),
+            &version.to_le_bytes(),
+        ],
+        &CERTIFICATE_SYSTEM_ID,
+    )
+}
+
+/// HolderClaim PDA of a certificate and verifier challenge
+pub fn find_holder_claim_pda(certificate_hash: &[u8; 32], challenge: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"holder_claim", certificate_hash, challenge],
+        &CERTIFICATE_SYSTEM_ID,
+    )
+}
+
+/// InstituteRegistry PDA
+pub fn find_institute_registry_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"institute_registry"], &INSTITUTE_VALIDATOR_ID)
+}
+
+/// GovernanceConfig PDA
+pub fn find_governance_config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"governance_config"], &INSTITUTE_VALIDATOR_ID)
+}
+
+/// InstituteEntry PDA of an institute
+pub fn find_institute_entry_pda(institute: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"institute", institute.as_ref()], &INSTITUTE_VALIDATOR_ID)
+}
+
+/// InstituteProfile PDA of an institute
+pub fn find_institute_profile_pda(institute: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"institute_profile", institute.as_ref()],
+        &INSTITUTE_VALIDATOR_ID,
+    )
+}
+
+/// InstituteKey PDA of a signing key (institute key or delegate)
+pub fn find_institute_key_pda(key: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"institute_key", key.as_ref()], &INSTITUTE_VALIDATOR_ID)
+}
+
+/// Election identified by its kind and subject, independent of the round
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Election {
+    /// Admission of a candidate institute
+    Admission(Pubkey),
+    /// Removal of a registered institute
+    Removal(Pubkey),
+    /// Change of the governance parameters
+    Governance,
+}
+
+impl Election {
+    /// Seed prefix of the election's VotingState PDAs
+    pub fn seed_prefix(&self) -> &'static [u8] {
+        match self {
+            Election::Admission(_) => b"voting_state",
+            Election::Removal(_) => b"removal_election",
+            Election::Governance => b"governance_election",
+        }
+    }
+
+    /// Subject of the election (the GovernanceConfig PDA for governance elections)
+    pub fn subject(&self) -> Pubkey {
+        match self {
+            Election::Admission(institute) | Election::Removal(institute) => *institute,
+            Election::Governance => find_governance_config_pda().0,
+        }
+    }
+}
+
+/// VotingState PDA of an election round
+pub fn find_voting_state_pda(election: &Election, round: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            election.seed_prefix(),
+            election.subject().as_ref(),
+            &round.to_le_bytes(),
+        ],
+        &INSTITUTE_VALIDATOR_ID,
+    )
+}
+
+/// ElectionHistory PDA of an election
+pub fn find_election_history_pda(election: &Election) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"election_history",
+            election.seed_prefix(),
+            election.subject().as_ref(),
+        ],
+        &INSTITUTE_VALIDATOR_ID,
+    )
+}
+
+// ============================================================================
+// Certificate System Instructions
+// ============================================================================
+
+fn instruction(
+    program_id: Pubkey,
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Accounts an issuance writes besides the certificate, resolved by
+/// `CertValClient::issuance`
+#[derive(Clone, Copy, Debug)]
+pub struct Issuance {
+    /// Serial the certificate is indexed under (the institute's next serial)
+    pub serial: u64,
+    /// Current CertificateFilter generation (None before the filter is initialized)
+    pub certificate_filter: Option<Pubkey>,
+}
+
+/// Issues a certificate signed by `issuer` (institute key or delegate) of `institute`
+pub fn add_certificate(
+    issuer: &Pubkey,
+    institute: &Pubkey,
+    issuance: &Issuance,
+    certificate_hash: [u8; 32],
+    expires_at: Option<i64>,
+    holder: Option<CertificateHolder>,
+    credential_schema: Option<Pubkey>,
+) -> Instruction {
+    instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::AddCertificate {
+            certificate: find_certificate_pda(&certificate_hash).0,
+            issuer: *issuer,
+            institute_validator_program: INSTITUTE_VALIDATOR_ID,
+            institute_registry: find_institute_registry_pda().0,
+            issuer_key: find_institute_key_pda(issuer).0,
+            issuer_entry: find_institute_entry_pda(institute).0,
+            issuer_stats: find_issuer_stats_pda(institute).0,
+            certificate_index: find_certificate_index_pda(institute, issuance.serial).0,
+            filter_state: find_certificate_filter_state_pda().0,
+            certificate_filter: issuance.certificate_filter,
+            credential_schema,
+            system_program: system_program::ID,
+        },
+        certificate_system::instruction::AddCertificate {
+            certificate_hash,
+            expires_at,
+            holder,
+        },
+    )
+}
+
+/// Replaces the certificate `old_hash` with a new certificate `new_hash`
+pub fn correct_certificate(
+    issuer: &Pubkey,
+    institute: &Pubkey,
+    issuance: &Issuance,
+    old_hash: [u8; 32],
+    new_hash: [u8; 32],
+) -> Instruction {
+    instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::CorrectCertificate {
+            old_certificate_pda: find_certificate_pda(&old_hash).0,
+            new_certificate: find_certificate_pda(&new_hash).0,
+            issuer: *issuer,
+            institute_validator_program: INSTITUTE_VALIDATOR_ID,
+            institute_registry: find_institute_registry_pda().0,
+            issuer_key: find_institute_key_pda(issuer).0,
+            issuer_entry: find_institute_entry_pda(institute).0,
+            issuer_stats: find_issuer_stats_pda(institute).0,
+            certificate_index: find_certificate_index_pda(institute, issuance.serial).0,
+            filter_state: find_certificate_filter_state_pda().0,
+            certificate_filter: issuance.certificate_filter,
+            system_program: system_program::ID,
+        },
+        certificate_system::instruction::CorrectCertificate { old_hash, new_hash },
+    )
+}
+
+/// Revokes a certificate of `institute` without issuing a replacement
+pub fn revoke_certificate(
+    issuer: &Pubkey,
+    institute: &Pubkey,
+    certificate_hash: [u8; 32],
+    reason: RevocationReason,
+    note_hash: Option<[u8; 32]>,
+) -> Instruction {
+    instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::RevokeCertificate {
+            certificate: find_certificate_pda(&certificate_hash).0,
+            issuer: *issuer,
+            institute_validator_program: INSTITUTE_VALIDATOR_ID,
+            institute_registry: find_institute_registry_pda().0,
+            issuer_key: find_institute_key_pda(issuer).0,
+            issuer_stats: find_issuer_stats_pda(institute).0,
+            system_program: system_program::ID,
+        },
+        certificate_system::instruction::RevokeCertificate {
+            certificate_hash,
+            reason,
+            note_hash,
+        },
+    )
+}
+
+/// Extends the expiry of a certificate that has `renewal_count` renewals so far
+pub fn extend_certificate_expiry(
+    issuer: &Pubkey,
+    institute: &Pubkey,
+    certificate_hash: [u8; 32],
+    renewal_count: u16,
+    new_expires_at: i64,
+) -> Instruction {
+    instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::ExtendCertificateExpiry {
+            certificate: find_certificate_pda(&certificate_hash).0,
+            renewal: find_certificate_renewal_pda(&certificate_hash, renewal_count).0,
+            issuer: *issuer,
+            institute_validator_program: INSTITUTE_VALIDATOR_ID,
+            institute_registry: find_institute_registry_pda().0,
+            issuer_key: find_institute_key_pda(issuer).0,
+            issuer_entry: find_institute_entry_pda(institute).0,
+            system_program: system_program::ID,
+        },
+        certificate_system::instruction::ExtendCertificateExpiry {
+            certificate_hash,
+            new_expires_at,
+        },
+    )
+}
+
+/// View instruction returning the CertificateStatus of a certificate issued by `issuer`
+pub fn verify_certificate(
+    certificate_hash: &[u8; 32],
+    issuer: &Pubkey,
+    with_profile: bool,
+    credential_schema: Option<Pubkey>,
+) -> Instruction {
+    instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::VerifyCertificate {
+            certificate: find_certificate_pda(certificate_hash).0,
+            institute_validator_program: INSTITUTE_VALIDATOR_ID,
+            institute_registry: find_institute_registry_pda().0,
+            issuer_entry: find_institute_entry_pda(issuer).0,
+            governance_config: find_governance_config_pda().0,
+            issuer_profile: with_profile.then(|| find_institute_profile_pda(issuer).0),
+            credential_schema,
+        },
+        certificate_system::instruction::VerifyCertificate {},
+    )
+}
+
+/// Fails unless the certificate issued by `issuer` is currently valid
+pub fn require_valid_certificate(
+    certificate_hash: &[u8; 32],
+    issuer: &Pubkey,
+    expected_issuer: Option<Pubkey>,
+    expected_schema: Option<Pubkey>,
+) -> Instruction {
+    instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::RequireValidCertificate {
+            certificate: find_certificate_pda(certificate_hash).0,
+            institute_validator_program: INSTITUTE_VALIDATOR_ID,
+            institute_registry: find_institute_registry_pda().0,
+            issuer_entry: find_institute_entry_pda(issuer).0,
+        },
+        certificate_system::instruction::RequireValidCertificate {
+            expected_issuer,
+            expected_schema,
+        },
+    )
+}
+
+/// View instruction returning the CertificateLineage of `certificate_hash`
+/// `chain` lists every version of the certificate, from the original to the head
+pub fn get_certificate_lineage(certificate_hash: &[u8; 32], chain: &[[u8; 32]]) -> Instruction {
+    let mut instruction = instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::GetCertificateLineage {
+            certificate: find_certificate_pda(certificate_hash).0,
+            institute_validator_program: INSTITUTE_VALIDATOR_ID,
+            institute_registry: find_institute_registry_pda().0,
+        },
+        certificate_system::instruction::GetCertificateLineage {},
+    );
+    instruction.accounts.extend(
+        chain
+            .iter()
+            .map(|hash| AccountMeta::new_readonly(find_certificate_pda(hash).0, false)),
+    );
+    instruction
+}
+
+/// View instruction returning `institute`'s `serial`-th certificate
+/// `certificate_hash` is the hash recorded in the serial's CertificateIndex
+pub fn get_issuer_certificate(
+    institute: &Pubkey,
+    serial: u64,
+    certificate_hash: &[u8; 32],
+) -> Instruction {
+    instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::GetIssuerCertificate {
+            issuer_stats: find_issuer_stats_pda(institute).0,
+            certificate_index: find_certificate_index_pda(institute, serial).0,
+            certificate: find_certificate_pda(certificate_hash).0,
+        },
+        certificate_system::instruction::GetIssuerCertificate { serial },
+    )
+}
+
+/// Registers a credential schema for `institute`
+pub fn register_credential_schema(
+    issuer: &Pubkey,
+    institute: &Pubkey,
+    params: CredentialSchemaParams,
+) -> Instruction {
+    instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::RegisterCredentialSchema {
+            credential_schema: find_credential_schema_pda(institute, &params.name, params.version)
+                .0,
+            issuer: *issuer,
+            institute_validator_program: INSTITUTE_VALIDATOR_ID,
+            institute_registry: find_institute_registry_pda().0,
+            issuer_key: find_institute_key_pda(issuer).0,
+            issuer_entry: find_institute_entry_pda(institute).0,
+            system_program: system_program::ID,
+        },
+        certificate_system::instruction::RegisterCredentialSchema {
+            institute: *institute,
+            params,
+        },
+    )
+}
+
+/// Proves that `holder` holds a certificate, answering a verifier `challenge`
+pub fn prove_holdership(
+    holder: &Pubkey,
+    certificate_hash: [u8; 32],
+    challenge: [u8; 32],
+    salt: Option<[u8; 32]>,
+) -> Instruction {
+    instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::ProveHoldership {
+            certificate: find_certificate_pda(&certificate_hash).0,
+            holder_claim: find_holder_claim_pda(&certificate_hash, &challenge).0,
+            holder: *holder,
+            institute_validator_program: INSTITUTE_VALIDATOR_ID,
+            institute_registry: find_institute_registry_pda().0,
+            system_program: system_program::ID,
+        },
+        certificate_system::instruction::ProveHoldership {
+            certificate_hash,
+            challenge,
+            salt,
+        },
+    )
+}
+
+/// Anchors a Merkle root of `leaf_count` certificate hashes
+pub fn add_certificate_batch(
+    issuer: &Pubkey,
+    institute: &Pubkey,
+    merkle_root: [u8; 32],
+    leaf_count: u32,
+    expires_at: Option<i64>,
+) -> Instruction {
+    instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::AddCertificateBatch {
+            batch: find_certificate_batch_pda(institute, &merkle_root).0,
+            issuer: *issuer,
+            institute_validator_program: INSTITUTE_VALIDATOR_ID,
+            institute_registry: find_institute_registry_pda().0,
+            issuer_key: find_institute_key_pda(issuer).0,
+            issuer_entry: find_institute_entry_pda(institute).0,
+            system_program: system_program::ID,
+        },
+        certificate_system::instruction::AddCertificateBatch {
+            merkle_root,
+            leaf_count,
+            expires_at,
+        },
+    )
+}
+
+/// Revokes a single leaf of a batch anchored by `institute`
+#[allow(clippy::too_many_arguments)]
+pub fn revoke_batch_leaf(
+    issuer: &Pubkey,
+    institute: &Pubkey,
+    merkle_root: &[u8; 32],
+    leaf_hash: [u8; 32],
+    leaf_index: u32,
+    proof: Vec<[u8; 32]>,
+    reason: RevocationReason,
+    note_hash: Option<[u8; 32]>,
+) -> Instruction {
+    let batch = find_certificate_batch_pda(institute, merkle_root).0;
+    instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::RevokeBatchLeaf {
+            batch,
+            revocation: find_batch_revocation_pda(&batch, leaf_index).0,
+            issuer: *issuer,
+            institute_validator_program: INSTITUTE_VALIDATOR_ID,
+            institute_registry: find_institute_registry_pda().0,
+            issuer_key: find_institute_key_pda(issuer).0,
+            issuer_stats: find_issuer_stats_pda(institute).0,
+            system_program: system_program::ID,
+        },
+        certificate_system::instruction::RevokeBatchLeaf {
+            leaf_hash,
+            leaf_index,
+            proof,
+            reason,
+            note_hash,
+        },
+    )
+}
+
+/// View instruction returning the CertificateStatus of a batch leaf
+/// Pass `revoked` when the leaf's BatchLeafRevocation account exists
+pub fn verify_in_batch(
+    merkle_root: &[u8; 32],
+    issuer: &Pubkey,
+    leaf_hash: [u8; 32],
+    leaf_index: u32,
+    proof: Vec<[u8; 32]>,
+    revoked: bool,
+    with_profile: bool,
+) -> Instruction {
+    let batch = find_certificate_batch_pda(issuer, merkle_root).0;
+    instruction(
+        CERTIFICATE_SYSTEM_ID,
+        certificate_system::accounts::VerifyInBatch {
+            batch,
+            revocation: revoked.then(|| find_batch_revocation_pda(&batch, leaf_index).0),
+            institute_validator_program: INSTITUTE_VALIDATOR_ID,
+            institute_registry: find_institute_registry_pda().0,
+            issuer_entry: find_institute_entry_pda(issuer).0,
+            governance_config: find_governance_config_pda().0,
+            issuer_profile: with_profile.then(|| find_institute_profile_pda(issuer).0),
+        },
+        certificate_system::instruction::VerifyInBatch {
+            leaf_hash,
+            leaf_index,
+            proof,
+        },
+    )
+}
+
+/// Creates the account of a new CertificateFilter generation, to be sent together with
+/// `initialize_certificate_filter` or `rotate_certificate_filter`
+/// `lamports` is the rent exemption of CertificateFilter::LEN bytes
+pub fn create_certificate_filter_account(
+    payer: &Pubkey,
+    filter: &Pubkey,
+    lamports: u64,
+) -> Instruction {
+    system_instruction::create_account(
+        payer,
+        filter,
+        lamports,
+        CertificateFilter::LEN as u64,
+        &CERTIFICATE_SYSTEM_ID,
+    )
+}
+
+/// Makes the freshly created `filter` the first CertificateFilter generation
+/// Signed by registry authorities meeting the threshold
+pub fn initialize_certificate_filter(
+    authority: &Pubkey,
+    filter: &Pubkey,
+    co_signers: &[Pubkey],
+) -> Instruction {
+    with_co_signers(
+        instruction(
+            CERTIFICATE_SYSTEM_ID,
+            certificate_system::accounts::InitializeCertificateFilter {
+                filter_state: find_certificate_filter_state_pda().0,
+                filter: *filter,
+                institute_validator_program: INSTITUTE_VALIDATOR_ID,
+                institute_registry: find_institute_registry_pda().0,
+                authority: *authority,
+                system_program: system_program::ID,
+            },
+            certificate_system::instruction::InitializeCertificateFilter {},
+        ),
+        co_signers,
+    )
+}
+
+/// Replaces the CertificateFilter generation `current_filter` with the freshly created `filter`
+/// Signed by registry authorities meeting the threshold
+pub fn rotate_certificate_filter(
+    authority: &Pubkey,
+    current_filter: &Pubkey,
+    filter: &Pubkey,
+    co_signers: &[Pubkey],
+) -> Instruction {
+    with_co_signers(
+        instruction(
+            CERTIFICATE_SYSTEM_ID,
+            certificate_system::accounts::RotateCertificateFilter {
+                filter_state: find_certificate_filter_state_pda().0,
+                current_filter: *current_filter,
+                filter: *filter,
+                institute_validator_program: INSTITUTE_VALIDATOR_ID,
+                institute_registry: find_institute_registry_pda().0,
+                authority: *authority,
+            },
+            certificate_system::instruction::RotateCertificateFilter {},
+        ),
+        co_signers,
+    )
+}
+
+// ============================================================================
+// Institute Validator Instructions
+// ============================================================================
+
+/// Appends additional authorities co-signing an M-of-N authority instruction
+fn with_co_signers(mut instruction: Instruction, co_signers: &[Pubkey]) -> Instruction {
+    instruction
+        .accounts
+        .extend(co_signers.iter().map(|key| AccountMeta::new_readonly(*key, true)));
+    instruction
+}
+
+/// Creates the registry with its initial institutes and governance parameters
+pub fn initialize_registry(
+    authority: &Pubkey,
+    initial_institutes: Vec<Pubkey>,
+    governance: GovernanceParams,
+) -> Instruction {
+    let mut instruction = instruction(
+        INSTITUTE_VALIDATOR_ID,
+        institute_validator::accounts::InitializeRegistry {
+            institute_registry: find_institute_registry_pda().0,
+            governance_config: find_governance_config_pda().0,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        institute_validator::instruction::InitializeRegistry {
+            initial_institutes: initial_institutes.clone(),
+            governance,
+        },
+    );
+    // Entry, profile and key PDAs of every initial institute, as triples
+    instruction
+        .accounts
+        .extend(initial_institutes.iter().flat_map(|institute| {
+            [
+                AccountMeta::new(find_institute_entry_pda(institute).0, false),
+                AccountMeta::new(find_institute_profile_pda(institute).0, false),
+                AccountMeta::new(find_institute_key_pda(institute).0, false),
+            ]
+        }));
+    instruction
+}
+
+/// Opens the next admission election round for a candidate institute
+pub fn new_institute_election(
+    proposer: &Pubkey,
+    candidate_institute: Pubkey,
+    round: u32,
+    voting_period: i64,
+) -> Instruction {
+    let election = Election::Admission(candidate_institute);
+    instruction(
+        INSTITUTE_VALIDATOR_ID,
+        institute_validator::accounts::NewInstituteElection {
+            institute_registry: find_institute_registry_pda().0,
+            election_history: find_election_history_pda(&election).0,
+            voting_state: find_voting_state_pda(&election, round).0,
+            governance_config: find_governance_config_pda().0,
+            candidate_entry: find_institute_entry_pda(&candidate_institute).0,
+            candidate_key: find_institute_key_pda(&candidate_institute).0,
+            proposer: *proposer,
+            system_program: system_program::ID,
+        },
+        institute_validator::instruction::NewInstituteElection {
+            candidate_institute,
+            voting_period,
+        },
+    )
+}
+
+/// Opens the next governance election round proposing new governance parameters
+pub fn new_governance_election(
+    proposer: &Pubkey,
+    params: GovernanceParams,
+    round: u32,
+    voting_period: i64,
+) -> Instruction {
+    let election = Election::Governance;
+    instruction(
+        INSTITUTE_VALIDATOR_ID,
+        institute_validator::accounts::NewGovernanceElection {
+            institute_registry: find_institute_registry_pda().0,
+            election_history: find_election_history_pda(&election).0,
+            voting_state: find_voting_state_pda(&election, round).0,
+            governance_config: find_governance_config_pda().0,
+            proposer: *proposer,
+            system_program: system_program::ID,
+        },
+        institute_validator::instruction::NewGovernanceElection {
+            params,
+            voting_period,
+        },
+    )
+}
+
+/// Opens the next removal election round for a registered institute
+pub fn remove_institute(
+    authority: &Pubkey,
+    institute_to_remove: Pubkey,
+    round: u32,
+    voting_period: i64,
+    co_signers: &[Pubkey],
+) -> Instruction {
+    let election = Election::Removal(institute_to_remove);
+    with_co_signers(
+        instruction(
+            INSTITUTE_VALIDATOR_ID,
+            institute_validator::accounts::RemoveInstitute {
+                institute_registry: find_institute_registry_pda().0,
+                election_history: find_election_history_pda(&election).0,
+                voting_state: find_voting_state_pda(&election, round).0,
+                governance_config: find_governance_config_pda().0,
+                institute_entry: find_institute_entry_pda(&institute_to_remove).0,
+                authority: *authority,
+                system_program: system_program::ID,
+            },
+            institute_validator::instruction::RemoveInstitute {
+                institute_to_remove,
+                voting_period,
+            },
+        ),
+        co_signers,
+    )
+}
+
+/// Subject accounts the program needs to apply the outcome of an election
+fn subject_accounts(election: &Election) -> (Option<Pubkey>, Option<Pubkey>, Option<Pubkey>) {
+    match election {
+        Election::Admission(institute) => (
+            Some(find_institute_entry_pda(institute).0),
+            Some(find_institute_profile_pda(institute).0),
+            Some(find_institute_key_pda(institute).0),
+        ),
+        Election::Removal(institute) => (Some(find_institute_entry_pda(institute).0), None, None),
+        Election::Governance => (None, None, None),
+    }
+}
+
+/// Casts a vote signed by `voter` (a current key of `voter_institute`)
+pub fn vote(
+    voter: &Pubkey,
+    voter_institute: &Pubkey,
+    election: &Election,
+    round: u32,
+    vote_for: bool,
+) -> Instruction {
+    let (subject_entry, subject_profile, subject_key) = subject_accounts(election);
+    instruction(
+        INSTITUTE_VALIDATOR_ID,
+        institute_validator::accounts::Vote {
+            voting_state: find_voting_state_pda(election, round).0,
+            election_history: find_election_history_pda(election).0,
+            institute_registry: find_institute_registry_pda().0,
+            governance_config: find_governance_config_pda().0,
+            voter_key: find_institute_key_pda(voter).0,
+            voter_entry: find_institute_entry_pda(voter_institute).0,
+            subject_entry,
+            subject_profile,
+            subject_key,
+            voter: *voter,
+            system_program: system_program::ID,
+        },
+        institute_validator::instruction::Vote { vote_for },
+    )
+}
+
+/// Concludes an election round whose voting period has ended
+pub fn finalize_election(payer: &Pubkey, election: &Election, round: u32) -> Instruction {
+    let (subject_entry, subject_profile, subject_key) = subject_accounts(election);
+    instruction(
+        INSTITUTE_VALIDATOR_ID,
+        institute_validator::accounts::FinalizeElection {
+            voting_state: find_voting_state_pda(election, round).0,
+            election_history: find_election_history_pda(election).0,
+            institute_registry: find_institute_registry_pda().0,
+            governance_config: find_governance_config_pda().0,
+            subject_entry,
+            subject_profile,
+            subject_key,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        institute_validator::instruction::FinalizeElection {},
+    )
+}
+
+/// Closes a concluded election round, refunding its proposer
+pub fn close_election(proposer: &Pubkey, election: &Election, round: u32) -> Instruction {
+    instruction(
+        INSTITUTE_VALIDATOR_ID,
+        institute_validator::accounts::CloseElection {
+            voting_state: find_voting_state_pda(election, round).0,
+            proposer: *proposer,
+        },
+        institute_validator::instruction::CloseElection {},
+    )
+}
+
+/// View instruction returning the VotingStateView of an election round
+pub fn get_voting_state(election: &Election, round: u32) -> Instruction {
+    instruction(
+        INSTITUTE_VALIDATOR_ID,
+        institute_validator::accounts::GetVotingState {
+            voting_state: find_voting_state_pda(election, round).0,
+        },
+        institute_validator::instruction::GetVotingState {},
+    )
+}
+
+/// Proposes a new registry authority set
+pub fn propose_authority_transfer(
+    authority: &Pubkey,
+    new_authorities: Vec<Pubkey>,
+    new_threshold: u8,
+    co_signers: &[Pubkey],
+) -> Instruction {
+    with_co_signers(
+        instruction(
+            INSTITUTE_VALIDATOR_ID,
+            institute_validator::accounts::ProposeAuthorityTransfer {
+                institute_registry: find_institute_registry_pda().0,
+                authority: *authority,
+            },
+            institute_validator::instruction::ProposeAuthorityTransfer {
+                new_authorities,
+                new_threshold,
+            },
+        ),
+        co_signers,
+    )
+}
+
+/// Accepts the pending authority transfer
+pub fn accept_authority_transfer(authority: &Pubkey, co_signers: &[Pubkey]) -> Instruction {
+    with_co_signers(
+        instruction(
+            INSTITUTE_VALIDATOR_ID,
+            institute_validator::accounts::AcceptAuthorityTransfer {
+                institute_registry: find_institute_registry_pda().0,
+                authority: *authority,
+            },
+            institute_validator::instruction::AcceptAuthorityTransfer {},
+        ),
+        co_signers,
+    )
+}
+
+/// Replaces the emergency guardians
+pub fn set_guardians(
+    authority: &Pubkey,
+    guardians: Vec<Pubkey>,
+    co_signers: &[Pubkey],
+) -> Instruction {
+    with_co_signers(
+        instruction(
+            INSTITUTE_VALIDATOR_ID,
+            institute_validator::accounts::SetGuardians {
+                institute_registry: find_institute_registry_pda().0,
+                authority: *authority,
+            },
+            institute_validator::instruction::SetGuardians { guardians },
+        ),
+        co_signers,
+    )
+}
+
+/// Sets the paused scopes (`InstituteRegistry::PAUSE_*` bits)
+pub fn set_paused_scopes(
+    authority: &Pubkey,
+    paused_scopes: u8,
+    co_signers: &[Pubkey],
+) -> Instruction {
+    with_co_signers(
+        instruction(
+            INSTITUTE_VALIDATOR_ID,
+            institute_validator::accounts::SetPausedScopes {
+                institute_registry: find_institute_registry_pda().0,
+                authority: *authority,
+            },
+            institute_validator::instruction::SetPausedScopes { paused_scopes },
+        ),
+        co_signers,
+    )
+}
+
+/// Rotates the primary signing key of `institute` to `new_key`
+pub fn rotate_institute_key(
+    institute: &Pubkey,
+    current_key: &Pubkey,
+    new_key: &Pubkey,
+    retire_current_key_at: i64,
+) -> Instruction {
+    instruction(
+        INSTITUTE_VALIDATOR_ID,
+        institute_validator::accounts::RotateInstituteKey {
+            institute_entry: find_institute_entry_pda(institute).0,
+            current_key_record: find_institute_key_pda(current_key).0,
+            new_key_record: find_institute_key_pda(new_key).0,
+            current_key: *current_key,
+            new_key: *new_key,
+            new_key_entry: find_institute_entry_pda(new_key).0,
+            new_key_election_history: find_election_history_pda(&Election::Admission(*new_key)).0,
+            system_program: system_program::ID,
+        },
+        institute_validator::instruction::RotateInstituteKey {
+            retire_current_key_at,
+        },
+    )
+}
+
+/// Authorizes `delegate` to act for `institute`, signed by the institute's current key
+pub fn add_institute_delegate(
+    institute: &Pubkey,
+    institute_signer: &Pubkey,
+    delegate: &Pubkey,
+    permissions: u8,
+    expires_at: Option<i64>,
+) -> Instruction {
+    instruction(
+        INSTITUTE_VALIDATOR_ID,
+        institute_validator::accounts::AddInstituteDelegate {
+            institute_entry: find_institute_entry_pda(institute).0,
+            institute_key: find_institute_key_pda(institute_signer).0,
+            delegate_key_record: find_institute_key_pda(delegate).0,
+            institute: *institute_signer,
+            delegate: *delegate,
+            system_program: system_program::ID,
+        },
+        institute_validator::instruction::AddInstituteDelegate {
+            permissions,
+            expires_at,
+        },
+    )
+}
+
+/// Revokes `delegate`, signed by the current key of `institute`
+pub fn revoke_institute_delegate(
+    institute: &Pubkey,
+    institute_signer: &Pubkey,
+    delegate: &Pubkey,
+) -> Instruction {
+    instruction(
+        INSTITUTE_VALIDATOR_ID,
+        institute_validator::accounts::RevokeInstituteDelegate {
+            institute_entry: find_institute_entry_pda(institute).0,
+            delegate_key_record: find_institute_key_pda(delegate).0,
+            institute: *institute_signer,
+        },
+        institute_validator::instruction::RevokeInstituteDelegate {},
+    )
+}
+
+/// Updates the profile of `institute`, signed by its current key
+pub fn update_institute_profile(
+    institute: &Pubkey,
+    institute_signer: &Pubkey,
+    params: InstituteProfileParams,
+) -> Instruction {
+    instruction(
+        INSTITUTE_VALIDATOR_ID,
+        institute_validator::accounts::UpdateInstituteProfile {
+            institute_profile: find_institute_profile_pda(institute).0,
+            institute_entry: find_institute_entry_pda(institute).0,
+            institute_key: find_institute_key_pda(institute_signer).0,
+            institute: *institute_signer,
+        },
+        institute_validator::instruction::UpdateInstituteProfile { params },
+    )
+}
+
+// ============================================================================
+// RPC Client
+// ============================================================================
+
+/// Thin wrapper around an RpcClient that decodes program accounts and errors
+pub struct CertValClient {
+    pub rpc: RpcClient,
+}
+
+impl CertValClient {
+    pub fn new(rpc_url: impl ToString) -> Self {
+        Self {
+            rpc: RpcClient::new(rpc_url.to_string()),
+        }
+    }
+
+    /// Fetch and decode an Anchor account, returning None if it does not exist
+    pub fn fetch<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<Option<T>, ClientError> {
+        let account = self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())?
+            .value;
+        match account {
+            Some(account) => Ok(Some(T::try_deserialize(&mut account.data.as_slice())?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetch and decode every account of type `T` owned by `program_id`
+    /// This scans the whole program; prefer direct PDA lookups where possible
+    pub fn fetch_all<T: AccountDeserialize + Discriminator>(
+        &self,
+        program_id: &Pubkey,
+    ) -> Result<Vec<(Pubkey, T)>, ClientError> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                0,
+                T::DISCRIMINATOR.to_vec(),
+            ))]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(self.rpc.commitment()),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+        self.rpc
+            .get_program_accounts_with_config(program_id, config)?
+            .into_iter()
+            .map(|(address, account)| {
+                Ok((address, T::try_deserialize(&mut account.data.as_slice())?))
+            })
+            .collect()
+    }
+
+    /// Fetch and decode several accounts; missing accounts are None
+    /// Addresses are requested in chunks of the RPC limit of 100 accounts
+    pub fn fetch_multiple<T: AccountDeserialize>(
+        &self,
+        addresses: &[Pubkey],
+    ) -> Result<Vec<Option<T>>, ClientError> {
+        let mut accounts = Vec::with_capacity(addresses.len());
+        for chunk in addresses.chunks(100) {
+            for account in self
+                .rpc
+                .get_multiple_accounts_with_commitment(chunk, self.rpc.commitment())?
+                .value
+            {
+                accounts.push(match account {
+                    Some(account) => Some(T::try_deserialize(&mut account.data.as_slice())?),
+                    None => None,
+                });
+            }
+        }
+        Ok(accounts)
+    }
+
+    /// Simulate a view instruction and decode its return data
+    pub fn simulate_view<T: AnchorDeserialize>(
+        &self,
+        instruction: Instruction,
+        payer: &Pubkey,
+    ) -> Result<T, ClientError> {
+        let instructions = [instruction];
+        let transaction = Transaction::new_with_payer(&instructions, Some(payer));
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            commitment: Some(self.rpc.commitment()),
+            ..RpcSimulateTransactionConfig::default()
+        };
+        let result = self
+            .rpc
+            .simulate_transaction_with_config(&transaction, config)?
+            .value;
+
+        if let Some(err) = result.err {
+            let logs = result.logs.unwrap_or_default();
+            return Err(ClientError::from_transaction_error(err, &logs));
+        }
+        let (data, _) = result.return_data.ok_or(ClientError::MissingReturnData)?.data;
+        let data = BASE64_STANDARD
+            .decode(data)
+            .map_err(|_| ClientError::MissingReturnData)?;
+        Ok(T::deserialize(&mut data.as_slice())?)
+    }
+
+    /// Verify a certificate through the program's `verify_certificate` view
+    /// Returns None if no certificate exists for the hash
+    pub fn verify_certificate(
+        &self,
+        certificate_hash: &[u8; 32],
+        payer: &Pubkey,
+    ) -> Result<Option<certificate_system::CertificateStatus>, ClientError> {
+        let Some(certificate) = self.fetch_certificate(certificate_hash)? else {
+            return Ok(None);
+        };
+        let with_profile = self
+            .rpc
+            .get_account_with_commitment(
+                &find_institute_profile_pda(&certificate.issuer).0,
+                self.rpc.commitment(),
+            )?
+            .value
+            .is_some();
+        let instruction = verify_certificate(
+            certificate_hash,
+            &certificate.issuer,
+            with_profile,
+            certificate.schema,
+        );
+        self.simulate_view(instruction, payer).map(Some)
+    }
+
+    /// Fetch the Certificate of a certificate hash
+    pub fn fetch_certificate(
+        &self,
+        certificate_hash: &[u8; 32],
+    ) -> Result<Option<certificate_system::Certificate>, ClientError> {
+        self.fetch(&find_certificate_pda(certificate_hash).0)
+    }
+
+    /// Fetch the IssuerStats of an institute (None before its first certificate)
+    pub fn fetch_issuer_stats(
+        &self,
+        institute: &Pubkey,
+    ) -> Result<Option<certificate_system::IssuerStats>, ClientError> {
+        self.fetch(&find_issuer_stats_pda(institute).0)
+    }
+
+    /// Serial the institute's next certificate will be indexed under
+    pub fn next_serial(&self, institute: &Pubkey) -> Result<u64, ClientError> {
+        Ok(self
+            .fetch_issuer_stats(institute)?
+            .map_or(0, |stats| stats.next_serial))
+    }
+
+    /// Resolve the accounts the institute's next issuance writes
+    pub fn issuance(&self, institute: &Pubkey) -> Result<Issuance, ClientError> {
+        Ok(Issuance {
+            serial: self.next_serial(institute)?,
+            certificate_filter: self
+                .fetch_certificate_filter_state()?
+                .map(|filter_state| filter_state.current),
+        })
+    }
+
+    /// Fetch the CertificateFilterState (None before the filter is initialized)
+    pub fn fetch_certificate_filter_state(
+        &self,
+    ) -> Result<Option<certificate_system::CertificateFilterState>, ClientError> {
+        self.fetch(&find_certificate_filter_state_pda().0)
+    }
+
+    /// Fetch a CertificateFilter generation
+    /// The filter is copied into a heap allocation, as it is too large for the stack
+    pub fn fetch_certificate_filter(
+        &self,
+        address: &Pubkey,
+    ) -> Result<Option<Box<CertificateFilter>>, ClientError> {
+        let Some(account) = self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())?
+            .value
+        else {
+            return Ok(None);
+        };
+        let data = account.data.as_slice();
+        if account.owner != CERTIFICATE_SYSTEM_ID
+            || data.len() != CertificateFilter::LEN
+            || !data.starts_with(CertificateFilter::DISCRIMINATOR)
+        {
+            return Err(ClientError::Decode(
+                anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into(),
+            ));
+        }
+        let mut filter: Box<CertificateFilter> = bytemuck::zeroed_box();
+        bytemuck::bytes_of_mut(filter.as_mut())
+            .copy_from_slice(&data[CertificateFilter::DISCRIMINATOR.len()..]);
+        Ok(Some(filter))
+    }
+
+    /// Fetch every CertificateFilter generation, newest first
+    /// A certificate hash is absent from the registry (outside of batches) when none of
+    /// them contains it
+    pub fn fetch_certificate_filters(
+        &self,
+    ) -> Result<Vec<(Pubkey, Box<CertificateFilter>)>, ClientError> {
+        let mut filters = Vec::new();
+        let Some(filter_state) = self.fetch_certificate_filter_state()? else {
+            return Ok(filters);
+        };
+        let mut address = filter_state.current;
+        loop {
+            let filter = self
+                .fetch_certificate_filter(&address)?
+                .ok_or(ClientError::MissingCertificateFilter(address))?;
+            let previous = filter.previous;
+            let is_first = filter.generation == 0;
+            filters.push((address, filter));
+            if is_first {
+                return Ok(filters);
+            }
+            address = previous;
+        }
+    }
+
+    /// Fetch up to `limit` certificates of an institute in serial order, starting at `start`
+    /// Reads the CertificateIndex and then the Certificate accounts in two batched requests
+    pub fn fetch_issuer_certificates(
+        &self,
+        institute: &Pubkey,
+        start: u64,
+        limit: u64,
+    ) -> Result<Vec<(u64, certificate_system::Certificate)>, ClientError> {
+        let end = self.next_serial(institute)?.min(start.saturating_add(limit));
+        let index_addresses: Vec<Pubkey> = (start..end)
+            .map(|serial| find_certificate_index_pda(institute, serial).0)
+            .collect();
+        let indexes = self
+            .fetch_multiple::<certificate_system::CertificateIndex>(&index_addresses)?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let certificate_addresses: Vec<Pubkey> = indexes
+            .iter()
+            .map(|index| find_certificate_pda(&index.certificate_hash).0)
+            .collect();
+        let certificates = self.fetch_multiple(&certificate_addresses)?;
+        Ok(indexes
+            .iter()
+            .zip(certificates)
+            .filter_map(|(index, certificate)| Some((index.serial, certificate?)))
+            .collect())
+    }
+
+    /// Walk the correction chain of a certificate in both directions
+    /// Fails with `LineageTooDeep` once the chain grows beyond `max_depth` versions
+    pub fn fetch_lineage(
+        &self,
+        certificate_hash: &[u8; 32],
+        max_depth: usize,
+    ) -> Result<Option<Lineage>, ClientError> {
+        let Some(certificate) = self.fetch_certificate(certificate_hash)? else {
+            return Ok(None);
+        };
+        let mut certificates = VecDeque::from([certificate]);
+        let fetch_link = |hash: [u8; 32], len: usize| {
+            if len >= max_depth {
+                return Err(ClientError::LineageTooDeep(max_depth));
+            }
+            self.fetch_certificate(&hash)?
+                .ok_or(ClientError::BrokenLineage(find_certificate_pda(&hash).0))
+        };
+
+        // Back to the original issuance, then forward to the head
+        while let Some(previous_hash) = certificates[0].previous_hash {
+            certificates.push_front(fetch_link(previous_hash, certificates.len())?);
+        }
+        let position = certificates.len() - 1;
+        while let Some(replacement_hash) = certificates[certificates.len() - 1].replacement_hash {
+            certificates.push_back(fetch_link(replacement_hash, certificates.len())?);
+        }
+
+        Ok(Some(Lineage {
+            certificates: certificates.into(),
+            position,
+        }))
+    }
+
+    /// Fetch the VotingState of an election round
+    pub fn fetch_voting_state(
+        &self,
+        election: &Election,
+        round: u32,
+    ) -> Result<Option<institute_validator::VotingState>, ClientError> {
+        self.fetch(&find_voting_state_pda(election, round).0)
+    }
+
+    /// Fetch the ElectionHistory of an election
+    pub fn fetch_election_history(
+        &self,
+        election: &Election,
+    ) -> Result<Option<institute_validator::ElectionHistory>, ClientError> {
+        self.fetch(&find_election_history_pda(election).0)
+    }
+
+    /// Fetch the InstituteRegistry
+    pub fn fetch_registry(
+        &self,
+    ) -> Result<Option<institute_validator::InstituteRegistry>, ClientError> {
+        self.fetch(&find_institute_registry_pda().0)
+    }
+
+    /// Fetch the GovernanceConfig
+    pub fn fetch_governance_config(
+        &self,
+    ) -> Result<Option<institute_validator::GovernanceConfig>, ClientError> {
+        self.fetch(&find_governance_config_pda().0)
+    }
+
+    /// Fetch the InstituteEntry of an institute
+    pub fn fetch_institute_entry(
+        &self,
+        institute: &Pubkey,
+    ) -> Result<Option<institute_validator::InstituteEntry>, ClientError> {
+        self.fetch(&find_institute_entry_pda(institute).0)
+    }
+
+    /// Sign, send and confirm a transaction, decoding program errors
+    pub fn send(
+        &self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> Result<Signature, ClientError> {
+        let mut all_signers = vec![payer];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &all_signers,
+            self.rpc.get_latest_blockhash()?,
+        );
+        self.rpc
+            .send_and_confirm_transaction(&transaction)
+            .map_err(ClientError::from_rpc)
+    }
+}
+
+/// Correction chain of a certificate, as walked by `CertValClient::fetch_lineage`
+pub struct Lineage {
+    /// Every version of the certificate, from the original issuance to the head
+    pub certificates: Vec<certificate_system::Certificate>,
+    /// Index in `certificates` of the queried certificate
+    pub position: usize,
+}
+
+impl Lineage {
+    /// Latest version of the certificate
+    pub fn head(&self) -> &certificate_system::Certificate {
+        self.certificates.last().expect("a lineage holds at least one certificate")
+    }
+
+    /// Certificate hashes in chain order, as passed to `get_certificate_lineage`
+    pub fn hashes(&self) -> Vec<[u8; 32]> {
+        self.certificates
+            .iter()
+            .map(|certificate| certificate.certificate_hash)
+            .collect()
+    }
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Error returned by one of the two programs
+#[derive(Clone, Copy, Debug)]
+pub enum ProgramError {
+    Certificate(CertificateError),
+    Validator(ValidatorError),
+}
+
+impl ProgramError {
+    /// Custom error code raised by the program
+    pub fn code(&self) -> u32 {
+        match self {
+            ProgramError::Certificate(err) => (*err).into(),
+            ProgramError::Validator(err) => (*err).into(),
+        }
+    }
+}
+
+// Anchor error enums do not derive PartialEq, so errors compare by program and code
+impl PartialEq for ProgramError {
+    fn eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other) && self.code() == other.code()
+    }
+}
+
+impl Eq for ProgramError {}
+
+impl std::fmt::Display for ProgramError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProgramError::Certificate(err) => write!(f, "{}: {}", err.name(), err),
+            ProgramError::Validator(err) => write!(f, "{}: {}", err.name(), err),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    #[error("program error {0}")]
+    Program(ProgramError),
+
+    #[error("rpc error: {0}")]
+    Rpc(Box<RpcError>),
+
+    #[error("transaction failed: {0}")]
+    Transaction(TransactionError),
+
+    #[error("account could not be decoded: {0}")]
+    Decode(#[from] anchor_lang::error::Error),
+
+    #[error("return data could not be decoded: {0}")]
+    ReturnData(#[from] std::io::Error),
+
+    #[error("view instruction returned no data")]
+    MissingReturnData,
+
+    #[error("correction chain is longer than {0} versions")]
+    LineageTooDeep(usize),
+
+    #[error("correction chain links to missing certificate {0}")]
+    BrokenLineage(Pubkey),
+
+    #[error("certificate filter account {0} does not exist")]
+    MissingCertificateFilter(Pubkey),
+}
+
+impl From<RpcError> for ClientError {
+    fn from(err: RpcError) -> Self {
+        ClientError::Rpc(Box::new(err))
+    }
+}
+
+impl ClientError {
+    /// Decode a failed transaction into the error of the program that raised it
+    /// Only preflight failures carry the logs needed to tell which program that was
+    pub fn from_rpc(err: RpcError) -> Self {
+        let logs = match err.kind() {
+            RpcErrorKind::RpcError(RpcRequestError::RpcResponseError {
+                data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+                ..
+            }) => result.logs.clone(),
+            _ => None,
+        };
+        match err.get_transaction_error() {
+            Some(tx_err) => {
+                Self::from_transaction_error(tx_err, logs.as_deref().unwrap_or_default())
+            }
+            None => ClientError::Rpc(Box::new(err)),
+        }
+    }
+
+    /// Decode a transaction error, finding the program that raised it in the transaction logs
+    /// The top-level instruction's program may only have relayed the error of a CPI
+    pub fn from_transaction_error(err: TransactionError, logs: &[String]) -> Self {
+        if let TransactionError::InstructionError(_, InstructionError::Custom(code)) = &err {
+            let program_error = failing_program(logs)
+                .and_then(|program_id| decode_error(&program_id, *code));
+            if let Some(program_error) = program_error {
+                return ClientError::Program(program_error);
+            }
+        }
+        ClientError::Transaction(err)
+    }
+
+    /// Program error, if the transaction failed in one of the two programs
+    pub fn program_error(&self) -> Option<ProgramError> {
+        match self {
+            ClientError::Program(err) => Some(*err),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! error_table {
+    ($name:ident, $error:ident, [$($variant:ident),* $(,)?]) => {
+        /// Every variant in declaration order, so the offset of a variant is its code
+        const $name: &[$error] = &[$($error::$variant),*];
+    };
+}
+
+error_table!(CERTIFICATE_ERRORS, CertificateError, [
+    IssuerNotRegistered,
+    UnauthorizedIssuer,
+    InvalidCertificateHash,
+    CertificateAlreadyInvalid,
+    InvalidInstituteRegistry,
+    InvalidExpiry,
+    CertificateDoesNotExpire,
+    RenewalLimitReached,
+    InvalidBatchSize,
+    InvalidMerkleProof,
+    IssuerKeyRetired,
+    MissingIssuerPermission,
+    ProgramPaused,
+    CertificateNotValid,
+    CertificateExpired,
+    UnexpectedIssuer,
+    HolderNotBound,
+    HolderMismatch,
+    MissingHolderSalt,
+    InvalidSchemaName,
+    InvalidCredentialSchema,
+    UnexpectedSchema,
+    LineageTooDeep,
+    InvalidLineage,
+    InvalidIssuerIndex,
+    FilterRotated,
+    FilterNotFull,
+    MissingCertificateFilter,
+    InvalidBatchAccount,
+    AuthorityThresholdNotMet,
+]);
+
+error_table!(VALIDATOR_ERRORS, ValidatorError, [
+    InstituteAlreadyRegistered,
+    VoterNotRegistered,
+    AlreadyVoted,
+    VotingNotActive,
+    Unauthorized,
+    InstituteNotFound,
+    InstituteEntryMismatch,
+    ProfileFieldTooLong,
+    InvalidCountryCode,
+    KeyRetired,
+    KeyAlreadyInUse,
+    NotPrimarySigningKey,
+    InvalidKeyRetirement,
+    InvalidDelegatePermissions,
+    InvalidDelegateExpiry,
+    NotADelegate,
+    MissingKeyPermission,
+    InvalidVotingPeriod,
+    VotingPeriodEnded,
+    VotingPeriodNotEnded,
+    InvalidApprovalThreshold,
+    InvalidQuorum,
+    MissingSubjectAccounts,
+    CannotVoteOnOwnRemoval,
+    CannotRemoveLastInstitute,
+    AuthorityThresholdNotMet,
+    InvalidAuthoritySet,
+    NoPendingAuthorityTransfer,
+    InvalidGuardianSet,
+    InvalidPauseScopes,
+    ProgramPaused,
+    ElectionAlreadyOpen,
+    ElectionStillActive,
+    RegistryFull,
+    VoterNotInElectorate,
+]);
+
+/// Program that raised the error of a failed transaction, read from its logs
+/// The error propagates up through every invoking program, each logging its own
+/// `Program <id> failed` line, so the first such line names the program that raised it
+pub fn failing_program(logs: &[String]) -> Option<Pubkey> {
+    logs.iter().find_map(|line| {
+        let (program_id, outcome) = line.strip_prefix("Program ")?.split_once(' ')?;
+        if !outcome.starts_with("failed") {
+            return None;
+        }
+        program_id.parse().ok()
+    })
+}
+
+/// Map a custom error code raised by `program_id` to its program error
+pub fn decode_error(program_id: &Pubkey, code: u32) -> Option<ProgramError> {
+    let index = code.checked_sub(anchor_lang::error::ERROR_CODE_OFFSET)? as usize;
+    if *program_id == CERTIFICATE_SYSTEM_ID {
+        CERTIFICATE_ERRORS.get(index).copied().map(ProgramError::Certificate)
+    } else if *program_id == INSTITUTE_VALIDATOR_ID {
+        VALIDATOR_ERRORS.get(index).copied().map(ProgramError::Validator)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,282 @@
+use anchor_lang::error::ERROR_CODE_OFFSET;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+use cert_val_client::*;
+
+#[test]
+fn error_tables_match_program_codes() {
+    for (program_id, count) in [(CERTIFICATE_SYSTEM_ID, 30), (INSTITUTE_VALIDATOR_ID, 35)] {
+        for index in 0..count {
+            let code = ERROR_CODE_OFFSET + index;
+            let err = decode_error(&program_id, code).expect("code is mapped");
+            assert_eq!(err.code(), code);
+        }
+        assert!(decode_error(&program_id, ERROR_CODE_OFFSET + count).is_none());
+    }
+
+    assert_eq!(
+        decode_error(&CERTIFICATE_SYSTEM_ID, CertificateError::ProgramPaused.into()),
+        Some(ProgramError::Certificate(CertificateError::ProgramPaused))
+    );
+    assert!(decode_error(&Pubkey::new_unique(), ERROR_CODE_OFFSET).is_none());
+}
+
+#[test]
+fn vote_targets_the_election_round() {
+    let voter = Pubkey::new_unique();
+    let candidate = Pubkey::new_unique();
+    let election = Election::Admission(candidate);
+    let instruction = vote(&voter, &voter, &election, 2, true);
+
+    assert_eq!(instruction.program_id, INSTITUTE_VALIDATOR_ID);
+    assert_eq!(
+        &instruction.data[..8],
+        institute_validator::instruction::Vote::DISCRIMINATOR
+    );
+    assert_eq!(instruction.data[8], 1);
+    assert_eq!(instruction.accounts[0].pubkey, find_voting_state_pda(&election, 2).0);
+    assert_eq!(instruction.accounts[1].pubkey, find_election_history_pda(&election).0);
+    assert_eq!(instruction.accounts[6].pubkey, find_institute_entry_pda(&candidate).0);
+}
+
+#[test]
+fn lineage_view_passes_the_chain_in_order() {
+    let chain = [[1u8; 32], [2u8; 32], [3u8; 32]];
+    let instruction = get_certificate_lineage(&chain[1], &chain);
+
+    assert_eq!(instruction.program_id, CERTIFICATE_SYSTEM_ID);
+    assert_eq!(instruction.accounts[0].pubkey, find_certificate_pda(&chain[1]).0);
+    let links: Vec<_> = instruction.accounts[3..].iter().collect();
+    assert_eq!(links.len(), chain.len());
+    for (link, hash) in links.iter().zip(&chain) {
+        assert_eq!(link.pubkey, find_certificate_pda(hash).0);
+        assert!(!link.is_writable && !link.is_signer);
+    }
+}
+
+#[test]
+fn issuance_writes_the_issuer_index_and_the_current_filter() {
+    let institute = Pubkey::new_unique();
+    let filter = Pubkey::new_unique();
+    let issuance = Issuance {
+        serial: 7,
+        certificate_filter: Some(filter),
+    };
+    let instruction =
+        add_certificate(&institute, &institute, &issuance, [4u8; 32], None, None, None);
+    let (stats, index) = (&instruction.accounts[6], &instruction.accounts[7]);
+
+    assert_eq!(stats.pubkey, find_issuer_stats_pda(&institute).0);
+    assert_eq!(index.pubkey, find_certificate_index_pda(&institute, 7).0);
+    assert!(stats.is_writable && index.is_writable);
+    assert_eq!(instruction.accounts[8].pubkey, find_certificate_filter_state_pda().0);
+    assert_eq!(instruction.accounts[9].pubkey, filter);
+    assert!(instruction.accounts[9].is_writable);
+    assert_ne!(
+        find_certificate_index_pda(&institute, 7).0,
+        find_certificate_index_pda(&institute, 8).0
+    );
+
+    // Before the filter is initialized, the optional account is left out
+    let issuance = Issuance {
+        serial: 0,
+        certificate_filter: None,
+    };
+    let instruction =
+        add_certificate(&institute, &institute, &issuance, [4u8; 32], None, None, None);
+    assert_eq!(instruction.accounts[9].pubkey, CERTIFICATE_SYSTEM_ID);
+}
+
+#[test]
+fn certificate_filter_has_no_false_negatives() {
+    let params = certificate_system::FilterParams::latest();
+    let mut filter: Box<CertificateFilter> = bytemuck::zeroed_box();
+    filter.num_bits = params.num_bits;
+    filter.num_hashes = params.num_hashes;
+    filter.max_fill_bps = params.max_fill_bps;
+
+    let hashes: Vec<[u8; 32]> = (0u32..1_000)
+        .map(|i| solana_sdk::hash::hashv(&[&i.to_le_bytes()]).to_bytes())
+        .collect();
+    for hash in &hashes {
+        filter.insert(hash);
+    }
+
+    assert!(hashes.iter().all(|hash| filter.contains(hash)));
+    assert_eq!(filter.inserted_count, 1_000);
+    assert!(filter.bits_set <= 1_000 * params.num_hashes as u64);
+    assert!(!filter.contains(&[0u8; 32]));
+    assert!(!filter.is_full());
+}
+
+#[test]
+fn voting_state_grows_with_every_vote() {
+    use anchor_lang::AccountSerialize;
+    use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+    use institute_validator::{ElectionKind, RemovedIssuerPolicy, VotingState, VotingStatus};
+
+    // Past the 50 members a fixed-size account used to hold, up to the heap bound
+    for (votes, votes_for) in [(0, 0), (3, 1), (51, 26), (VotingState::MAX_ELIGIBLE_VOTERS, 257)] {
+        let voting_state = VotingState {
+            kind: ElectionKind::GovernanceChange(GovernanceParams {
+                approval_threshold_bps: 10_000,
+                quorum_bps: 10_000,
+                min_voting_period: 3_600,
+                removed_issuer_policy: RemovedIssuerPolicy::Grandfather,
+            }),
+            subject: Pubkey::new_unique(),
+            round: 1,
+            proposer: Pubkey::new_unique(),
+            votes_for: vec![Pubkey::new_unique(); votes_for],
+            votes_against: vec![Pubkey::new_unique(); votes - votes_for],
+            total_eligible_voters: VotingState::MAX_ELIGIBLE_VOTERS as u32,
+            status: VotingStatus::Approved,
+            created_at: 0,
+            voting_ends_at: 0,
+            concluded_at: Some(0),
+            bump: 255,
+        };
+        let mut data = Vec::new();
+        voting_state.try_serialize(&mut data).unwrap();
+        // Room for every vote cast, and not for one more
+        assert!(data.len() <= VotingState::space(votes));
+        assert!(VotingState::space(votes) - data.len() < 32);
+    }
+
+    // Each vote is a single realloc well within the per-instruction growth limit
+    assert!(VotingState::space(1) - VotingState::space(0) <= MAX_PERMITTED_DATA_INCREASE);
+}
+
+#[test]
+fn approval_percentage_is_compared_in_basis_points() {
+    use institute_validator::{
+        ElectionKind, GovernanceConfig, RemovedIssuerPolicy, VotingState, VotingStatus,
+    };
+
+    // Two of three votes in favour: 6_666 bps
+    let voting_state = VotingState {
+        kind: ElectionKind::Admission,
+        subject: Pubkey::new_unique(),
+        round: 0,
+        proposer: Pubkey::new_unique(),
+        votes_for: vec![Pubkey::new_unique(); 2],
+        votes_against: vec![Pubkey::new_unique()],
+        total_eligible_voters: 3,
+        status: VotingStatus::Active,
+        created_at: 0,
+        voting_ends_at: 0,
+        concluded_at: None,
+        bump: 255,
+    };
+    assert_eq!(voting_state.approval_percentage(), 6_666);
+
+    let config = |approval_threshold_bps| GovernanceConfig {
+        approval_threshold_bps,
+        quorum_bps: 10_000,
+        min_voting_period: 3_600,
+        removed_issuer_policy: RemovedIssuerPolicy::Grandfather,
+        updated_at: 0,
+        bump: 255,
+    };
+    assert!(matches!(voting_state.outcome(&config(6_666)), VotingStatus::Approved));
+    assert!(matches!(voting_state.outcome(&config(6_667)), VotingStatus::Rejected));
+}
+
+#[test]
+fn open_admissions_hold_their_seats() {
+    use anchor_lang::AccountSerialize;
+    use institute_validator::{AuthorityTransfer, InstituteRegistry};
+
+    let mut registry = InstituteRegistry {
+        institute_count: 50,
+        authorities: vec![Pubkey::new_unique(); InstituteRegistry::MAX_AUTHORITIES],
+        authority_threshold: 1,
+        pending_transfer: Some(AuthorityTransfer {
+            authorities: vec![Pubkey::new_unique(); InstituteRegistry::MAX_AUTHORITIES],
+            threshold: 1,
+            proposed_at: 0,
+        }),
+        bump: 255,
+        guardians: vec![Pubkey::new_unique(); InstituteRegistry::MAX_GUARDIANS],
+        paused_scopes: 0,
+        open_admissions: 0,
+    };
+
+    // Admit the 51st institute onwards, one election at a time, up to the bound
+    while registry.institute_count < InstituteRegistry::MAX_INSTITUTES {
+        // new_institute_election
+        assert!(!registry.is_full());
+        registry.open_admissions += 1;
+        // conclude_election, approved
+        registry.open_admissions -= 1;
+        registry.institute_count += 1;
+    }
+    assert!(registry.is_full());
+
+    // Seats held by open admissions count against the bound, so every approval fits
+    registry.institute_count = 60;
+    registry.open_admissions = InstituteRegistry::MAX_INSTITUTES - 61;
+    assert!(!registry.is_full());
+    registry.open_admissions += 1;
+    assert!(registry.is_full());
+
+    // The registry does not grow with membership, even with every other list full
+    let mut data = Vec::new();
+    registry.try_serialize(&mut data).unwrap();
+    assert!(data.len() <= InstituteRegistry::LEN);
+}
+
+#[test]
+fn batches_are_namespaced_by_institute() {
+    let (institute, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let root = [9u8; 32];
+    let batch = find_certificate_batch_pda(&institute, &root).0;
+    assert_ne!(batch, find_certificate_batch_pda(&other, &root).0);
+
+    let add = add_certificate_batch(&institute, &institute, root, 4, None);
+    let revoke = revoke_batch_leaf(
+        &institute,
+        &institute,
+        &root,
+        [1u8; 32],
+        0,
+        Vec::new(),
+        RevocationReason::ClericalError,
+        None,
+    );
+    let verify = verify_in_batch(&root, &institute, [1u8; 32], 0, Vec::new(), false, false);
+    for instruction in [add, revoke, verify] {
+        assert_eq!(instruction.accounts[0].pubkey, batch);
+    }
+}
+
+#[test]
+fn errors_are_decoded_for_the_program_that_raised_them() {
+    use solana_sdk::instruction::InstructionError;
+    use solana_sdk::transaction::TransactionError;
+
+    let code = ERROR_CODE_OFFSET + 1;
+    let err = TransactionError::InstructionError(0, InstructionError::Custom(code));
+    // A certificate_system instruction relaying the failure of a validator CPI
+    let logs: Vec<String> = [
+        format!("Program {CERTIFICATE_SYSTEM_ID} invoke [1]"),
+        format!("Program {INSTITUTE_VALIDATOR_ID} invoke [2]"),
+        format!("Program {INSTITUTE_VALIDATOR_ID} failed: custom program error: {code:#x}"),
+        format!("Program {CERTIFICATE_SYSTEM_ID} failed: custom program error: {code:#x}"),
+    ]
+    .into();
+
+    assert_eq!(failing_program(&logs), Some(INSTITUTE_VALIDATOR_ID));
+    assert_eq!(
+        ClientError::from_transaction_error(err.clone(), &logs).program_error(),
+        Some(ProgramError::Validator(ValidatorError::VoterNotRegistered))
+    );
+    assert_eq!(
+        ClientError::from_transaction_error(err.clone(), &logs[..1]).program_error(),
+        None
+    );
+    assert_eq!(
+        ClientError::from_transaction_error(err, &logs[3..]).program_error(),
+        Some(ProgramError::Certificate(CertificateError::UnauthorizedIssuer))
+    );
+}
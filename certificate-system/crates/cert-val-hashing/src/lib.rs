@@ -0,0 +1,266 @@
+//! Canonical serialization and hashing of certificate documents
+//!
+//! Issuers and verifiers that hash the same marksheet must arrive at the same
+//! `certificate_hash`. This crate normalizes the certificate fields and encodes
+//! them in a fixed, versioned byte layout before hashing with SHA-256.
+//!
+//! Layout of `CanonicalVersion::V1`:
+//!
+//! ```text
+//! magic      b"CERTVAL" followed by the version byte 0x01
+//! field      tag: u8, length: u32 LE, value bytes (fields in tag order)
+//!   0x01     holder name          normalized name
+//!   0x02     holder identifier    normalized identifier (roll or registration number)
+//!   0x03     credential title     normalized name
+//!   0x04     issued on            ASCII "YYYY-MM-DD"
+//!   0x05     grades               count: u32 LE, then per grade, sorted by subject:
+//!                                 subject (u32 LE length + normalized name bytes),
+//!                                 grade (u32 LE length + normalized identifier bytes)
+//! ```
+//!
+//! Names are trimmed, have whitespace runs collapsed to a single space, and are
+//! uppercased. Identifiers and grades are stripped of all whitespace and
+//! uppercased. All text is finally brought into Unicode NFC.
+
+use solana_sha256_hasher::hashv;
+use std::fmt;
+use std::str::FromStr;
+use unicode_normalization::UnicodeNormalization;
+
+// ============================================================================
+// Versions
+// ============================================================================
+
+/// Version of the canonical serialization
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CanonicalVersion {
+    V1,
+}
+
+impl CanonicalVersion {
+    /// Magic prefix of every canonical encoding
+    pub const MAGIC: &'static [u8; 7] = b"CERTVAL";
+
+    /// Version byte following the magic prefix
+    pub fn as_byte(self) -> u8 {
+        match self {
+            CanonicalVersion::V1 => 1,
+        }
+    }
+
+    /// Ordered field names covered by the certificate hash
+    pub fn fields(self) -> &'static [&'static str] {
+        match self {
+            CanonicalVersion::V1 => &[
+                "holder_name",
+                "holder_id",
+                "credential_title",
+                "issued_on",
+                "grades",
+            ],
+        }
+    }
+
+    /// Hash of the ordered field list, as stored in a CredentialSchema's `field_list_hash`
+    pub fn field_list_hash(self) -> [u8; 32] {
+        field_list_hash(self.fields())
+    }
+}
+
+/// Hash an ordered list of field names (each as u32 LE length + UTF-8 bytes)
+pub fn field_list_hash(fields: &[&str]) -> [u8; 32] {
+    let mut encoded = Vec::new();
+    for field in fields {
+        push_bytes(&mut encoded, field.as_bytes());
+    }
+    hashv(&[&encoded]).to_bytes()
+}
+
+// ============================================================================
+// Document
+// ============================================================================
+
+/// Certificate fields as printed on the document
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CertificateDocument {
+    /// Full name of the holder
+    pub holder_name: String,
+    /// Roll or registration number of the holder at the institute
+    pub holder_id: String,
+    /// Title of the credential (programme, degree or course)
+    pub credential_title: String,
+    /// Date of issue
+    pub issued_on: Date,
+    /// Grades per subject, in any order
+    pub grades: Vec<Grade>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grade {
+    pub subject: String,
+    pub grade: String,
+}
+
+impl CertificateDocument {
+    /// Normalize the fields and encode them in the canonical layout of `version`
+    pub fn to_canonical_bytes(&self, version: CanonicalVersion) -> Result<Vec<u8>, HashingError> {
+        let mut grades = self
+            .grades
+            .iter()
+            .map(|grade| {
+                Ok((
+                    normalize_name(&grade.subject, "grades.subject")?,
+                    normalize_identifier(&grade.grade, "grades.grade")?,
+                ))
+            })
+            .collect::<Result<Vec<_>, HashingError>>()?;
+        grades.sort();
+        if let Some(pair) = grades.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            return Err(HashingError::DuplicateSubject(pair[0].0.clone()));
+        }
+
+        let mut encoded = Vec::with_capacity(256);
+        encoded.extend_from_slice(CanonicalVersion::MAGIC);
+        encoded.push(version.as_byte());
+
+        let holder_name = normalize_name(&self.holder_name, "holder_name")?;
+        let holder_id = normalize_identifier(&self.holder_id, "holder_id")?;
+        let credential_title = normalize_name(&self.credential_title, "credential_title")?;
+        push_field(&mut encoded, 0x01, holder_name.as_bytes());
+        push_field(&mut encoded, 0x02, holder_id.as_bytes());
+        push_field(&mut encoded, 0x03, credential_title.as_bytes());
+        push_field(&mut encoded, 0x04, self.issued_on.to_string().as_bytes());
+
+        let mut grade_bytes = Vec::new();
+        grade_bytes.extend_from_slice(&(grades.len() as u32).to_le_bytes());
+        for (subject, grade) in &grades {
+            push_bytes(&mut grade_bytes, subject.as_bytes());
+            push_bytes(&mut grade_bytes, grade.as_bytes());
+        }
+        push_field(&mut encoded, 0x05, &grade_bytes);
+
+        Ok(encoded)
+    }
+
+    /// SHA-256 of the canonical encoding, used as `certificate_hash` in `add_certificate`
+    pub fn certificate_hash(&self, version: CanonicalVersion) -> Result<[u8; 32], HashingError> {
+        Ok(hashv(&[&self.to_canonical_bytes(version)?]).to_bytes())
+    }
+}
+
+// ============================================================================
+// Normalization
+// ============================================================================
+
+/// Normalize a personal or programme name: trimmed, single spaces, uppercase, NFC
+pub fn normalize_name(value: &str, field: &'static str) -> Result<String, HashingError> {
+    // Uppercasing can decompose characters, so NFC is applied last
+    let normalized = value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase()
+        .nfc()
+        .collect::<String>();
+    if normalized.is_empty() {
+        return Err(HashingError::EmptyField(field));
+    }
+    Ok(normalized)
+}
+
+/// Normalize an identifier or grade: whitespace removed, uppercase, NFC
+pub fn normalize_identifier(value: &str, field: &'static str) -> Result<String, HashingError> {
+    let normalized = value
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase()
+        .nfc()
+        .collect::<String>();
+    if normalized.is_empty() {
+        return Err(HashingError::EmptyField(field));
+    }
+    Ok(normalized)
+}
+
+fn push_bytes(encoded: &mut Vec<u8>, bytes: &[u8]) {
+    encoded.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    encoded.extend_from_slice(bytes);
+}
+
+fn push_field(encoded: &mut Vec<u8>, tag: u8, value: &[u8]) {
+    encoded.push(tag);
+    push_bytes(encoded, value);
+}
+
+// ============================================================================
+// Dates
+// ============================================================================
+
+/// Calendar date, canonically written as "YYYY-MM-DD"
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Build a date, checking it exists in the Gregorian calendar
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, HashingError> {
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            _ => 0,
+        };
+        if day == 0 || day > days_in_month {
+            return Err(HashingError::InvalidDate(format!("{year:04}-{month:02}-{day:02}")));
+        }
+        Ok(Self { year, month, day })
+    }
+}
+
+impl FromStr for Date {
+    type Err = HashingError;
+
+    /// Parse a date in the canonical "YYYY-MM-DD" form
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || HashingError::InvalidDate(value.to_string());
+        let bytes = value.trim().as_bytes();
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err(invalid());
+        }
+        let number = |range: std::ops::Range<usize>| {
+            let digits = &bytes[range];
+            if !digits.iter().all(u8::is_ascii_digit) {
+                return Err(invalid());
+            }
+            Ok(digits.iter().fold(0u16, |n, d| n * 10 + u16::from(d - b'0')))
+        };
+        Date::new(number(0..4)?, number(5..7)? as u8, number(8..10)? as u8)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum HashingError {
+    #[error("Field `{0}` is empty after normalization")]
+    EmptyField(&'static str),
+
+    #[error("Invalid date `{0}`, expected an existing YYYY-MM-DD date")]
+    InvalidDate(String),
+
+    #[error("Subject `{0}` is graded more than once")]
+    DuplicateSubject(String),
+}
@@ -0,0 +1,161 @@
+//! Canonical hashing test vectors
+//!
+//! Any implementation of `CanonicalVersion::V1` (issuer tooling, verifiers, the
+//! web client) must reproduce these bytes and hashes exactly.
+
+use cert_val_hashing::{
+    normalize_identifier, normalize_name, CanonicalVersion, CertificateDocument, Date, Grade,
+    HashingError,
+};
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn document(
+    holder_name: &str,
+    holder_id: &str,
+    credential_title: &str,
+    issued_on: &str,
+    grades: &[(&str, &str)],
+) -> CertificateDocument {
+    CertificateDocument {
+        holder_name: holder_name.to_string(),
+        holder_id: holder_id.to_string(),
+        credential_title: credential_title.to_string(),
+        issued_on: issued_on.parse().unwrap(),
+        grades: grades
+            .iter()
+            .map(|(subject, grade)| Grade {
+                subject: subject.to_string(),
+                grade: grade.to_string(),
+            })
+            .collect(),
+    }
+}
+
+fn bsc_transcript() -> CertificateDocument {
+    document(
+        "Asha Verma",
+        "CS-2021-042",
+        "Bachelor of Science in Computer Science",
+        "2025-06-30",
+        &[("Data Structures", "A"), ("Algorithms", "A+")],
+    )
+}
+
+#[test]
+fn v1_field_list_hash() {
+    assert_eq!(
+        hex(&CanonicalVersion::V1.field_list_hash()),
+        "e47543e92bb6f7c7b1b8af9fe8bb585f787b2da1966b23f946287bdfe41c1ab1"
+    );
+}
+
+#[test]
+fn v1_canonical_bytes() {
+    let encoded = bsc_transcript()
+        .to_canonical_bytes(CanonicalVersion::V1)
+        .unwrap();
+
+    assert_eq!(
+        hex(&encoded),
+        concat!(
+            "4345525456414c01",
+            "010a00000041534841205645524d41",
+            "020b00000043532d323032312d303432",
+            "032700000042414348454c4f52204f4620534349454e434520494e20434f4d50555445522053",
+            "4349454e4345",
+            "040a000000323032352d30362d3330",
+            "053000000002000000",
+            "0a000000414c474f524954484d5302000000412b",
+            "0f0000004441544120535452554354555245530100000041",
+        )
+    );
+}
+
+#[test]
+fn v1_certificate_hash() {
+    assert_eq!(
+        hex(&bsc_transcript().certificate_hash(CanonicalVersion::V1).unwrap()),
+        "e277fa489cc37176923f945a502ad466c241cd9daf4b97a65845e9d3ef3f4196"
+    );
+}
+
+#[test]
+fn v1_hash_ignores_case_whitespace_and_grade_order() {
+    let messy = document(
+        "  asha   VERMA ",
+        "cs-2021-042 ",
+        "bachelor of  science in computer science",
+        "2025-06-30",
+        &[("algorithms", " a+ "), ("data  structures", "a")],
+    );
+
+    assert_eq!(
+        messy.certificate_hash(CanonicalVersion::V1).unwrap(),
+        bsc_transcript().certificate_hash(CanonicalVersion::V1).unwrap()
+    );
+}
+
+#[test]
+fn v1_hash_normalizes_unicode() {
+    let precomposed = document(
+        "Jos\u{e9} M\u{fc}ller",
+        "MAT/19/007",
+        "Master of Arts",
+        "2024-02-29",
+        &[],
+    );
+    let decomposed = document(
+        "jose\u{301} mu\u{308}ller",
+        "mat/19/007",
+        "master of arts",
+        "2024-02-29",
+        &[],
+    );
+
+    let expected = "525ac3513155c5eea9aa4e9eb504a041f2071c371babbb7da9c5eeebd58b43f9";
+    assert_eq!(hex(&precomposed.certificate_hash(CanonicalVersion::V1).unwrap()), expected);
+    assert_eq!(hex(&decomposed.certificate_hash(CanonicalVersion::V1).unwrap()), expected);
+}
+
+#[test]
+fn normalization_rules() {
+    assert_eq!(
+        normalize_name(" \tanne-marie  o'neil\n", "holder_name").unwrap(),
+        "ANNE-MARIE O'NEIL"
+    );
+    assert_eq!(normalize_identifier(" ab 12 / c ", "holder_id").unwrap(), "AB12/C");
+    assert_eq!(
+        normalize_name("   ", "holder_name"),
+        Err(HashingError::EmptyField("holder_name"))
+    );
+}
+
+#[test]
+fn dates_are_validated() {
+    assert_eq!("2024-02-29".parse::<Date>().unwrap().to_string(), "2024-02-29");
+    assert!("2023-02-29".parse::<Date>().is_err());
+    assert!("2100-02-29".parse::<Date>().is_err());
+    assert!("2000-02-29".parse::<Date>().is_ok());
+    assert!("2025-13-01".parse::<Date>().is_err());
+    assert!("2025-6-30".parse::<Date>().is_err());
+    assert!("30/06/2025".parse::<Date>().is_err());
+}
+
+#[test]
+fn duplicate_subjects_are_rejected() {
+    let duplicate = document(
+        "Asha Verma",
+        "CS-2021-042",
+        "Bachelor of Science",
+        "2025-06-30",
+        &[("Algorithms", "A"), ("  algorithms", "B")],
+    );
+
+    assert_eq!(
+        duplicate.certificate_hash(CanonicalVersion::V1),
+        Err(HashingError::DuplicateSubject("ALGORITHMS".to_string()))
+    );
+}
@@ -0,0 +1,381 @@
+//! `certval certificate`: issue, correct, verify and list certificates
+
+use crate::{hex, parse_hash, pubkey_value, CliError, Context};
+use cert_val_client::certificate_system::{
+    holder_commitment, CertificateState, CertificateStatus, HashingRule,
+};
+use cert_val_client::{find_certificate_pda, CertificateHolder, RevocationReason};
+use cert_val_hashing::{CanonicalVersion, CertificateDocument, Grade};
+use clap::{ArgGroup, Args, Subcommand};
+use serde::Deserialize;
+use serde_json::{json, Map};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Subcommand)]
+pub enum CertificateCommand {
+    /// Issue a certificate signed by the keypair (an institute key or delegate)
+    #[command(group(ArgGroup::new("holder").args(["holder_wallet", "holder_commitment"])))]
+    Issue {
+        #[command(flatten)]
+        source: CertificateSource,
+
+        /// Issuing institute [default: the keypair itself]
+        #[arg(long)]
+        institute: Option<Pubkey>,
+
+        /// Unix timestamp after which the certificate expires
+        #[arg(long)]
+        expires_at: Option<i64>,
+
+        /// Bind the certificate to this holder wallet
+        #[arg(long)]
+        holder_wallet: Option<Pubkey>,
+
+        /// Bind a salted commitment to the holder wallet instead of the wallet itself
+        #[arg(long, requires = "holder_wallet", value_parser = parse_hash)]
+        holder_salt: Option<[u8; 32]>,
+
+        /// Bind a precomputed holder commitment (64 hex characters)
+        #[arg(long, value_parser = parse_hash)]
+        holder_commitment: Option<[u8; 32]>,
+
+        /// CredentialSchema account the certificate hash was computed under
+        #[arg(long)]
+        schema: Option<Pubkey>,
+    },
+    /// Replace an existing certificate with a corrected one
+    Correct {
+        /// Hash of the certificate being replaced (64 hex characters)
+        #[arg(long, value_parser = parse_hash)]
+        old_hash: [u8; 32],
+
+        /// Corrected certificate
+        #[command(flatten)]
+        source: CertificateSource,
+
+        /// Issuing institute [default: the keypair itself]
+        #[arg(long)]
+        institute: Option<Pubkey>,
+    },
+    /// Verify a certificate; exits with a non-zero status unless it is valid
+    Verify {
+        #[command(flatten)]
+        source: CertificateSource,
+    },
+    /// List an institute's certificates in serial order, with its issuance counters
+    List {
+        /// Issuing institute [default: the keypair itself]
+        #[arg(long)]
+        institute: Option<Pubkey>,
+
+        /// First serial to list
+        #[arg(long, default_value_t = 0)]
+        start: u64,
+
+        /// Number of serials to list
+        #[arg(long, default_value_t = 50)]
+        limit: u64,
+    },
+}
+
+/// Where the certificate hash comes from
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+pub struct CertificateSource {
+    /// Document file whose raw bytes are hashed with SHA-256
+    #[arg(long)]
+    file: Option<PathBuf>,
+
+    /// JSON certificate document hashed with the canonical V1 serialization
+    #[arg(long)]
+    document: Option<PathBuf>,
+
+    /// Precomputed certificate hash (64 hex characters)
+    #[arg(long, value_parser = parse_hash)]
+    hash: Option<[u8; 32]>,
+}
+
+impl CertificateSource {
+    pub fn certificate_hash(&self) -> Result<[u8; 32], CliError> {
+        match (&self.file, &self.document, self.hash) {
+            (Some(path), _, _) => {
+                Ok(solana_sha256_hasher::hashv(&[&read_file(path)?]).to_bytes())
+            }
+            (_, Some(path), _) => {
+                Ok(read_document(path)?.certificate_hash(CanonicalVersion::V1)?)
+            }
+            (_, _, Some(hash)) => Ok(hash),
+            _ => unreachable!("clap requires one certificate source"),
+        }
+    }
+}
+
+/// JSON form of a CertificateDocument
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DocumentFile {
+    holder_name: String,
+    holder_id: String,
+    credential_title: String,
+    issued_on: String,
+    #[serde(default)]
+    grades: Vec<GradeFile>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GradeFile {
+    subject: String,
+    grade: String,
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, CliError> {
+    std::fs::read(path).map_err(|source| CliError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn read_document(path: &Path) -> Result<CertificateDocument, CliError> {
+    let invalid = |message: String| CliError::Document {
+        path: path.to_path_buf(),
+        message,
+    };
+    let file: DocumentFile =
+        serde_json::from_slice(&read_file(path)?).map_err(|err| invalid(err.to_string()))?;
+    Ok(CertificateDocument {
+        holder_name: file.holder_name,
+        holder_id: file.holder_id,
+        credential_title: file.credential_title,
+        issued_on: file.issued_on.parse()?,
+        grades: file
+            .grades
+            .into_iter()
+            .map(|grade| Grade {
+                subject: grade.subject,
+                grade: grade.grade,
+            })
+            .collect(),
+    })
+}
+
+pub fn run(context: &Context, command: CertificateCommand) -> Result<ExitCode, CliError> {
+    match command {
+        CertificateCommand::Issue {
+            source,
+            institute,
+            expires_at,
+            holder_wallet,
+            holder_salt,
+            holder_commitment: commitment,
+            schema,
+        } => {
+            let holder = match (holder_wallet, holder_salt, commitment) {
+                (Some(wallet), Some(salt), _) => {
+                    Some(CertificateHolder::Commitment(holder_commitment(&wallet, &salt)))
+                }
+                (Some(wallet), None, _) => Some(CertificateHolder::Wallet(wallet)),
+                (None, _, Some(commitment)) => Some(CertificateHolder::Commitment(commitment)),
+                (None, _, None) => None,
+            };
+            issue(context, &source, institute, expires_at, holder, schema)
+        }
+        CertificateCommand::Correct {
+            old_hash,
+            source,
+            institute,
+        } => correct(context, old_hash, &source, institute),
+        CertificateCommand::Verify { source } => verify(context, &source),
+        CertificateCommand::List {
+            institute,
+            start,
+            limit,
+        } => list(context, institute, start, limit),
+    }
+}
+
+fn issue(
+    context: &Context,
+    source: &CertificateSource,
+    institute: Option<Pubkey>,
+    expires_at: Option<i64>,
+    holder: Option<CertificateHolder>,
+    schema: Option<Pubkey>,
+) -> Result<ExitCode, CliError> {
+    let certificate_hash = source.certificate_hash()?;
+    let issuer = context.signer()?;
+    let institute = institute.unwrap_or(issuer.pubkey());
+
+    let issuance = context.client.issuance(&institute)?;
+    let instruction = cert_val_client::add_certificate(
+        &issuer.pubkey(),
+        &institute,
+        &issuance,
+        certificate_hash,
+        expires_at,
+        holder,
+        schema,
+    );
+    let signature = context.client.send(&[instruction], &issuer, &[])?;
+
+    let mut fields = Map::new();
+    fields.insert("certificate".into(), pubkey_value(&find_certificate_pda(&certificate_hash).0));
+    fields.insert("certificate_hash".into(), hex(&certificate_hash).into());
+    fields.insert("institute".into(), pubkey_value(&institute));
+    fields.insert("serial".into(), issuance.serial.into());
+    context.print_signature(signature, fields);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn correct(
+    context: &Context,
+    old_hash: [u8; 32],
+    source: &CertificateSource,
+    institute: Option<Pubkey>,
+) -> Result<ExitCode, CliError> {
+    let new_hash = source.certificate_hash()?;
+    let issuer = context.signer()?;
+    let institute = institute.unwrap_or(issuer.pubkey());
+
+    let issuance = context.client.issuance(&institute)?;
+    let instruction = cert_val_client::correct_certificate(
+        &issuer.pubkey(),
+        &institute,
+        &issuance,
+        old_hash,
+        new_hash,
+    );
+    let signature = context.client.send(&[instruction], &issuer, &[])?;
+
+    let mut fields = Map::new();
+    fields.insert("old_hash".into(), hex(&old_hash).into());
+    fields.insert("new_hash".into(), hex(&new_hash).into());
+    fields.insert("certificate".into(), pubkey_value(&find_certificate_pda(&new_hash).0));
+    fields.insert("serial".into(), issuance.serial.into());
+    context.print_signature(signature, fields);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn verify(context: &Context, source: &CertificateSource) -> Result<ExitCode, CliError> {
+    let certificate_hash = source.certificate_hash()?;
+    // The view is simulated, but the cluster still requires an existing fee payer
+    let payer = context.signer()?.pubkey();
+
+    let Some(status) = context.client.verify_certificate(&certificate_hash, &payer)? else {
+        context.print(&json!({
+            "certificate_hash": hex(&certificate_hash),
+            "state": "not-found",
+            "is_valid": false,
+        }));
+        return Ok(ExitCode::FAILURE);
+    };
+
+    context.print(&status_value(&status));
+    Ok(if status.is_valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn list(
+    context: &Context,
+    institute: Option<Pubkey>,
+    start: u64,
+    limit: u64,
+) -> Result<ExitCode, CliError> {
+    let institute = match institute {
+        Some(institute) => institute,
+        None => context.signer()?.pubkey(),
+    };
+    let stats = context
+        .client
+        .fetch_issuer_stats(&institute)?
+        .ok_or_else(|| CliError::NotFound(format!("{institute} has issued no certificates")))?;
+    let certificates = context
+        .client
+        .fetch_issuer_certificates(&institute, start, limit)?;
+
+    context.print(&json!({
+        "institute": institute.to_string(),
+        "issued_count": stats.issued_count,
+        "corrected_count": stats.corrected_count,
+        "revoked_count": stats.revoked_count,
+        "next_serial": stats.next_serial,
+        "certificates": certificates.iter().map(|(serial, certificate)| json!({
+            "serial": serial,
+            "certificate_hash": hex(&certificate.certificate_hash),
+            "version": certificate.version,
+            "is_valid": certificate.is_valid,
+            "issued_at": certificate.issued_at,
+            "corrected_at": certificate.corrected_at,
+            "revoked_at": certificate.revoked_at,
+        })).collect::<Vec<_>>(),
+    }));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn status_value(status: &CertificateStatus) -> serde_json::Value {
+    json!({
+        "certificate_hash": hex(&status.certificate_hash),
+        "state": state_name(&status.state),
+        "is_valid": status.is_valid,
+        "issuer": status.issuer.to_string(),
+        "issuer_status": crate::registry::status_name(&status.issuer_status),
+        "issuer_removed_at": status.issuer_removed_at,
+        "issued_by": status.issued_by.to_string(),
+        "issued_at": status.issued_at,
+        "expires_at": status.expires_at,
+        "renewal_count": status.renewal_count,
+        "holder": status.holder.as_ref().map(|holder| match holder {
+            CertificateHolder::Wallet(wallet) => json!({ "wallet": wallet.to_string() }),
+            CertificateHolder::Commitment(commitment) => json!({ "commitment": hex(commitment) }),
+        }),
+        "schema": status.schema.map(|schema| schema.to_string()),
+        "corrected_at": status.corrected_at,
+        "replacement_hash": status.replacement_hash.map(|hash| hex(&hash)),
+        "previous_hash": status.previous_hash.map(|hash| hex(&hash)),
+        "version": status.version,
+        "revoked_at": status.revoked_at,
+        "revocation_reason": status.revocation_reason.as_ref().map(reason_name),
+        "revocation_note_hash": status.revocation_note_hash.map(|hash| hex(&hash)),
+        "revoked_by": status.revoked_by.map(|key| key.to_string()),
+        "issuer_profile": status.issuer_profile.as_ref().map(|profile| json!({
+            "display_name": profile.display_name,
+            "country_code": String::from_utf8_lossy(&profile.country_code),
+            "accreditation_body": profile.accreditation_body,
+            "accreditation_id": profile.accreditation_id,
+            "uri": profile.uri,
+        })),
+        "credential_schema": status.credential_schema.as_ref().map(|schema| json!({
+            "name": schema.name,
+            "version": schema.version,
+            "hashing_rule": match schema.hashing_rule {
+                HashingRule::Sha256Document => "sha256-document",
+                HashingRule::CanonicalV1 => "canonical-v1",
+            },
+        })),
+    })
+}
+
+fn state_name(state: &CertificateState) -> &'static str {
+    match state {
+        CertificateState::Valid => "valid",
+        CertificateState::Expired => "expired",
+        CertificateState::Corrected => "corrected",
+        CertificateState::Revoked => "revoked",
+        CertificateState::IssuerRemoved => "issuer-removed",
+    }
+}
+
+fn reason_name(reason: &RevocationReason) -> &'static str {
+    match reason {
+        RevocationReason::Fraud => "fraud",
+        RevocationReason::ClericalError => "clerical-error",
+        RevocationReason::DegreeWithdrawn => "degree-withdrawn",
+        RevocationReason::Other => "other",
+    }
+}
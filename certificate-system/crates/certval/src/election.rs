@@ -0,0 +1,280 @@
+//! `certval election`: propose, vote on and list elections
+
+use crate::registry::{governance_value, GovernanceArgs};
+use crate::{load_keypair, pubkey_value, CliError, Context};
+use cert_val_client::institute_validator::{ElectionKind, VotingState, VotingStatus};
+use cert_val_client::{find_voting_state_pda, Election, INSTITUTE_VALIDATOR_ID};
+use clap::{ArgGroup, Args, Subcommand, ValueEnum};
+use serde_json::{json, Map, Value};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Subcommand)]
+pub enum ElectionCommand {
+    /// Open the next round of an election
+    Propose {
+        #[command(flatten)]
+        target: ElectionTarget,
+
+        /// Voting period in seconds [default: the governance minimum]
+        #[arg(long)]
+        voting_period: Option<i64>,
+
+        /// Proposed governance parameters (governance elections only)
+        #[command(flatten)]
+        governance: GovernanceArgs,
+
+        /// Additional registry authority keypairs co-signing a removal proposal
+        #[arg(long = "co-signer")]
+        co_signers: Vec<PathBuf>,
+    },
+    /// Vote in an election round as a key of a registered institute
+    #[command(group(ArgGroup::new("ballot").required(true).args(["approve", "against"])))]
+    Vote {
+        #[command(flatten)]
+        target: ElectionTarget,
+
+        /// Vote in favour
+        #[arg(long = "for")]
+        approve: bool,
+
+        /// Vote against
+        #[arg(long)]
+        against: bool,
+
+        /// Round to vote in [default: the latest round]
+        #[arg(long)]
+        round: Option<u32>,
+
+        /// Institute the keypair votes for [default: the keypair itself]
+        #[arg(long)]
+        institute: Option<Pubkey>,
+    },
+    /// List election rounds
+    List {
+        /// Only list elections of this kind
+        #[arg(long, value_enum)]
+        kind: Option<KindArg>,
+
+        /// Only list rounds that are still being voted on
+        #[arg(long)]
+        active: bool,
+    },
+}
+
+/// Election addressed by its kind and subject
+#[derive(Args)]
+pub struct ElectionTarget {
+    /// Kind of election
+    #[arg(value_enum)]
+    kind: KindArg,
+
+    /// Candidate (admission) or institute under review (removal); omitted for governance
+    subject: Option<Pubkey>,
+}
+
+#[derive(ValueEnum, Clone, Copy, PartialEq, Eq)]
+pub enum KindArg {
+    Admission,
+    Removal,
+    Governance,
+}
+
+impl ElectionTarget {
+    fn election(&self) -> Result<Election, CliError> {
+        match (self.kind, self.subject) {
+            (KindArg::Admission, Some(subject)) => Ok(Election::Admission(subject)),
+            (KindArg::Removal, Some(subject)) => Ok(Election::Removal(subject)),
+            (KindArg::Governance, None) => Ok(Election::Governance),
+            (KindArg::Governance, Some(_)) => Err(CliError::InvalidArgument(
+                "governance elections take no subject".to_string(),
+            )),
+            (_, None) => Err(CliError::InvalidArgument(
+                "admission and removal elections need the institute as subject".to_string(),
+            )),
+        }
+    }
+}
+
+pub fn run(context: &Context, command: ElectionCommand) -> Result<ExitCode, CliError> {
+    match command {
+        ElectionCommand::Propose {
+            target,
+            voting_period,
+            governance,
+            co_signers,
+        } => propose(context, &target, voting_period, &governance, &co_signers),
+        ElectionCommand::Vote {
+            target,
+            approve,
+            against: _,
+            round,
+            institute,
+        } => vote(context, &target, approve, round, institute),
+        ElectionCommand::List { kind, active } => list(context, kind, active),
+    }
+}
+
+fn propose(
+    context: &Context,
+    target: &ElectionTarget,
+    voting_period: Option<i64>,
+    governance: &GovernanceArgs,
+    co_signer_paths: &[PathBuf],
+) -> Result<ExitCode, CliError> {
+    let election = target.election()?;
+    if election != Election::Governance && governance.is_set() {
+        return Err(CliError::InvalidArgument(
+            "governance parameters only apply to governance elections".to_string(),
+        ));
+    }
+    if !matches!(election, Election::Removal(_)) && !co_signer_paths.is_empty() {
+        return Err(CliError::InvalidArgument(
+            "co-signers only apply to removal elections".to_string(),
+        ));
+    }
+
+    let proposer = context.signer()?;
+    let config = context
+        .client
+        .fetch_governance_config()?
+        .ok_or_else(|| CliError::NotFound("registry is not initialized".to_string()))?;
+    let voting_period = voting_period.unwrap_or(config.min_voting_period);
+    let round = context
+        .client
+        .fetch_election_history(&election)?
+        .map_or(0, |history| history.rounds);
+
+    let co_signers = co_signer_paths
+        .iter()
+        .map(load_keypair)
+        .collect::<Result<Vec<Keypair>, _>>()?;
+    let co_signer_keys: Vec<Pubkey> = co_signers.iter().map(Signer::pubkey).collect();
+    let signers: Vec<&dyn Signer> = co_signers.iter().map(|key| key as &dyn Signer).collect();
+
+    let mut fields = Map::new();
+    let instruction = match election {
+        Election::Admission(candidate) => cert_val_client::new_institute_election(
+            &proposer.pubkey(),
+            candidate,
+            round,
+            voting_period,
+        ),
+        Election::Removal(institute) => cert_val_client::remove_institute(
+            &proposer.pubkey(),
+            institute,
+            round,
+            voting_period,
+            &co_signer_keys,
+        ),
+        Election::Governance => {
+            let params = governance.params(Some(&config));
+            fields.insert("proposed".into(), governance_value(&params));
+            cert_val_client::new_governance_election(
+                &proposer.pubkey(),
+                params,
+                round,
+                voting_period,
+            )
+        }
+    };
+    let signature = context.client.send(&[instruction], &proposer, &signers)?;
+
+    fields.insert("voting_state".into(), pubkey_value(&find_voting_state_pda(&election, round).0));
+    fields.insert("kind".into(), kind_name(target.kind).into());
+    fields.insert("subject".into(), pubkey_value(&election.subject()));
+    fields.insert("round".into(), round.into());
+    fields.insert("voting_period".into(), voting_period.into());
+    context.print_signature(signature, fields);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn vote(
+    context: &Context,
+    target: &ElectionTarget,
+    vote_for: bool,
+    round: Option<u32>,
+    institute: Option<Pubkey>,
+) -> Result<ExitCode, CliError> {
+    let election = target.election()?;
+    let voter = context.signer()?;
+    let round = match round {
+        Some(round) => round,
+        None => context
+            .client
+            .fetch_election_history(&election)?
+            .and_then(|history| history.rounds.checked_sub(1))
+            .ok_or_else(|| CliError::NotFound("no election has been proposed".to_string()))?,
+    };
+    let institute = institute.unwrap_or(voter.pubkey());
+
+    let instruction =
+        cert_val_client::vote(&voter.pubkey(), &institute, &election, round, vote_for);
+    let signature = context.client.send(&[instruction], &voter, &[])?;
+
+    let mut fields = Map::new();
+    fields.insert("voting_state".into(), pubkey_value(&find_voting_state_pda(&election, round).0));
+    fields.insert("round".into(), round.into());
+    fields.insert("institute".into(), pubkey_value(&institute));
+    fields.insert("vote".into(), if vote_for { "for" } else { "against" }.into());
+    context.print_signature(signature, fields);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn list(context: &Context, kind: Option<KindArg>, active: bool) -> Result<ExitCode, CliError> {
+    let mut elections = context
+        .client
+        .fetch_all::<VotingState>(&INSTITUTE_VALIDATOR_ID)?;
+    elections.retain(|(_, state)| {
+        kind.is_none_or(|kind| kind == kind_of(&state.kind))
+            && (!active || state.status == VotingStatus::Active)
+    });
+    elections.sort_by_key(|(_, state)| state.created_at);
+
+    let rows: Vec<Value> = elections
+        .iter()
+        .map(|(address, state)| {
+            json!({
+                "address": address.to_string(),
+                "kind": kind_name(kind_of(&state.kind)),
+                "subject": state.subject.to_string(),
+                "round": state.round,
+                "status": status_name(&state.status),
+                "for": state.votes_for.len(),
+                "against": state.votes_against.len(),
+                "approval_percentage": state.approval_percentage(),
+                "eligible": state.total_eligible_voters,
+                "voting_ends_at": state.voting_ends_at,
+            })
+        })
+        .collect();
+    context.print(&Value::Array(rows));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn kind_of(kind: &ElectionKind) -> KindArg {
+    match kind {
+        ElectionKind::Admission => KindArg::Admission,
+        ElectionKind::Removal => KindArg::Removal,
+        ElectionKind::GovernanceChange(_) => KindArg::Governance,
+    }
+}
+
+fn kind_name(kind: KindArg) -> &'static str {
+    match kind {
+        KindArg::Admission => "admission",
+        KindArg::Removal => "removal",
+        KindArg::Governance => "governance",
+    }
+}
+
+fn status_name(status: &VotingStatus) -> &'static str {
+    match status {
+        VotingStatus::Active => "active",
+        VotingStatus::Approved => "approved",
+        VotingStatus::Rejected => "rejected",
+        VotingStatus::Expired => "expired",
+    }
+}
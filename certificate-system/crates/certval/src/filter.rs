@@ -0,0 +1,149 @@
+//! `certval filter`: create, rotate and query the certificate Bloom filter
+
+use crate::certificate::CertificateSource;
+use crate::{hex, load_keypair, pubkey_value, CliError, Context};
+use cert_val_client::certificate_system::FilterParams;
+use cert_val_client::{find_certificate_filter_state_pda, CertificateFilter, ClientError};
+use clap::Subcommand;
+use serde_json::{json, Map, Value};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Subcommand)]
+pub enum FilterCommand {
+    /// Create the first filter generation as a registry authority; the keypair pays its
+    /// rent (about 7.3 SOL)
+    Init {
+        /// Additional registry authority keypairs co-signing the initialization
+        #[arg(long = "co-signer")]
+        co_signers: Vec<PathBuf>,
+    },
+    /// Start a new filter generation once the current one is full, as a registry authority
+    Rotate {
+        /// Additional registry authority keypairs co-signing the rotation
+        #[arg(long = "co-signer")]
+        co_signers: Vec<PathBuf>,
+    },
+    /// Show every filter generation, newest first
+    Show,
+    /// Check whether a certificate may exist; exits with a non-zero status if it does not
+    Check {
+        #[command(flatten)]
+        source: CertificateSource,
+    },
+}
+
+pub fn run(context: &Context, command: FilterCommand) -> Result<ExitCode, CliError> {
+    match command {
+        FilterCommand::Init { co_signers } => create(context, None, &co_signers),
+        FilterCommand::Rotate { co_signers } => {
+            let filter_state = context
+                .client
+                .fetch_certificate_filter_state()?
+                .ok_or_else(|| CliError::NotFound("filter is not initialized".to_string()))?;
+            create(context, Some(filter_state.current), &co_signers)
+        }
+        FilterCommand::Show => show(context),
+        FilterCommand::Check { source } => check(context, &source),
+    }
+}
+
+/// Create a filter account and make it the first or, replacing `current`, the next generation
+fn create(
+    context: &Context,
+    current: Option<Pubkey>,
+    co_signer_paths: &[PathBuf],
+) -> Result<ExitCode, CliError> {
+    let payer = context.signer()?;
+    let filter = Keypair::new();
+    let co_signers = co_signer_paths
+        .iter()
+        .map(load_keypair)
+        .collect::<Result<Vec<Keypair>, _>>()?;
+    let co_signer_keys: Vec<Pubkey> = co_signers.iter().map(Signer::pubkey).collect();
+    let lamports = context
+        .client
+        .rpc
+        .get_minimum_balance_for_rent_exemption(CertificateFilter::LEN)
+        .map_err(ClientError::from)?;
+
+    let instructions = [
+        cert_val_client::create_certificate_filter_account(
+            &payer.pubkey(),
+            &filter.pubkey(),
+            lamports,
+        ),
+        match current {
+            Some(current) => cert_val_client::rotate_certificate_filter(
+                &payer.pubkey(),
+                &current,
+                &filter.pubkey(),
+                &co_signer_keys,
+            ),
+            None => cert_val_client::initialize_certificate_filter(
+                &payer.pubkey(),
+                &filter.pubkey(),
+                &co_signer_keys,
+            ),
+        },
+    ];
+    let mut signers: Vec<&dyn Signer> = vec![&filter];
+    signers.extend(co_signers.iter().map(|key| key as &dyn Signer));
+    let signature = context.client.send(&instructions, &payer, &signers)?;
+
+    let mut fields = Map::new();
+    fields.insert("filter".into(), pubkey_value(&filter.pubkey()));
+    fields.insert("previous".into(), current.as_ref().map_or(Value::Null, pubkey_value));
+    fields.insert("params_version".into(), FilterParams::latest().version.into());
+    context.print_signature(signature, fields);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn show(context: &Context) -> Result<ExitCode, CliError> {
+    let filters = context.client.fetch_certificate_filters()?;
+    if filters.is_empty() {
+        return Err(CliError::NotFound("filter is not initialized".to_string()));
+    }
+
+    context.print(&json!({
+        "address": find_certificate_filter_state_pda().0.to_string(),
+        "generations": filters.iter().map(|(address, filter)| json!({
+            "address": address.to_string(),
+            "generation": filter.generation,
+            "params_version": filter.params_version,
+            "num_bits": filter.num_bits,
+            "num_hashes": filter.num_hashes,
+            "inserted_count": filter.inserted_count,
+            "fill_bps": filter.fill_bps(),
+            "max_fill_bps": filter.max_fill_bps,
+            "created_at": filter.created_at,
+        })).collect::<Vec<_>>(),
+    }));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn check(context: &Context, source: &CertificateSource) -> Result<ExitCode, CliError> {
+    let certificate_hash = source.certificate_hash()?;
+    let filters = context.client.fetch_certificate_filters()?;
+    if filters.is_empty() {
+        return Err(CliError::NotFound("filter is not initialized".to_string()));
+    }
+
+    // Present in any generation means possibly issued; batch leaves are never inserted
+    let generation = filters
+        .iter()
+        .find(|(_, filter)| filter.contains(&certificate_hash))
+        .map(|(_, filter)| filter.generation);
+    context.print(&json!({
+        "certificate_hash": hex(&certificate_hash),
+        "may_exist": generation.is_some(),
+        "generation": generation,
+    }));
+    Ok(if generation.is_some() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
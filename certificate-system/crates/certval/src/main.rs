@@ -0,0 +1,178 @@
+//! `certval`: command-line tool for registrars and governance members
+//!
+//! Wraps `cert-val-client` to manage the institute registry and its elections,
+//! to issue, correct and verify certificates, and to maintain the certificate
+//! Bloom filter against any cluster (a local `solana-test-validator` by default).
+
+mod certificate;
+mod election;
+mod filter;
+mod output;
+mod registry;
+
+use cert_val_client::{CertValClient, ClientError};
+use cert_val_hashing::HashingError;
+use clap::{Parser, Subcommand};
+use output::OutputFormat;
+use serde_json::{Map, Value};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "certval", version, about)]
+struct Cli {
+    /// RPC URL of the cluster
+    #[arg(long, short = 'u', global = true, env = "CERTVAL_URL",
+        default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Keypair that signs and pays for transactions [default: ~/.config/solana/id.json]
+    #[arg(long, short = 'k', global = true, env = "CERTVAL_KEYPAIR")]
+    keypair: Option<PathBuf>,
+
+    /// Output format
+    #[arg(long, short = 'o', global = true, value_enum, default_value_t = OutputFormat::Table)]
+    output: OutputFormat,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Set up and inspect the institute registry
+    #[command(subcommand)]
+    Registry(registry::RegistryCommand),
+    /// Propose, vote on and list elections
+    #[command(subcommand)]
+    Election(election::ElectionCommand),
+    /// Issue, correct and verify certificates
+    #[command(subcommand)]
+    Certificate(certificate::CertificateCommand),
+    /// Manage and query the on-chain certificate Bloom filter
+    #[command(subcommand)]
+    Filter(filter::FilterCommand),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let context = Context {
+        client: CertValClient::new(&cli.url),
+        keypair_path: cli.keypair.unwrap_or_else(default_keypair_path),
+        output: cli.output,
+    };
+
+    let result = match cli.command {
+        Command::Registry(command) => registry::run(&context, command),
+        Command::Election(command) => election::run(&context, command),
+        Command::Certificate(command) => certificate::run(&context, command),
+        Command::Filter(command) => filter::run(&context, command),
+    };
+    match result {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// ============================================================================
+// Context
+// ============================================================================
+
+/// State shared by every command
+pub struct Context {
+    pub client: CertValClient,
+    pub keypair_path: PathBuf,
+    pub output: OutputFormat,
+}
+
+impl Context {
+    /// Load the signing keypair given by `--keypair`
+    pub fn signer(&self) -> Result<Keypair, CliError> {
+        load_keypair(&self.keypair_path)
+    }
+
+    /// Print a command result in the selected output format
+    pub fn print(&self, value: &Value) {
+        output::print(self.output, value);
+    }
+
+    /// Print the signature of a confirmed transaction with related accounts
+    pub fn print_signature(&self, signature: Signature, mut fields: Map<String, Value>) {
+        fields.insert("signature".to_string(), signature.to_string().into());
+        self.print(&Value::Object(fields));
+    }
+}
+
+fn default_keypair_path() -> PathBuf {
+    let home = std::env::var_os("HOME").unwrap_or_else(|| ".".into());
+    PathBuf::from(home).join(".config/solana/id.json")
+}
+
+pub fn load_keypair(path: &PathBuf) -> Result<Keypair, CliError> {
+    read_keypair_file(path).map_err(|err| CliError::Keypair {
+        path: path.clone(),
+        message: err.to_string(),
+    })
+}
+
+// ============================================================================
+// Parsing and Formatting
+// ============================================================================
+
+/// Parse a 32-byte hash given as 64 hex characters (an optional `0x` prefix is allowed)
+pub fn parse_hash(value: &str) -> Result<[u8; 32], String> {
+    let digits = value.strip_prefix("0x").unwrap_or(value);
+    if digits.len() != 64 || !digits.is_ascii() {
+        return Err("expected 64 hex characters".to_string());
+    }
+    let mut hash = [0u8; 32];
+    for (byte, pair) in hash.iter_mut().zip(digits.as_bytes().chunks(2)) {
+        let pair = std::str::from_utf8(pair).expect("ASCII input");
+        *byte = u8::from_str_radix(pair, 16).map_err(|_| format!("invalid hex `{pair}`"))?;
+    }
+    Ok(hash)
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+pub fn pubkey_value(key: &Pubkey) -> Value {
+    key.to_string().into()
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+
+    #[error("could not read keypair {}: {message}", path.display())]
+    Keypair { path: PathBuf, message: String },
+
+    #[error("could not read {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("invalid certificate document {}: {message}", path.display())]
+    Document { path: PathBuf, message: String },
+
+    #[error(transparent)]
+    Hashing(#[from] HashingError),
+
+    #[error("{0}")]
+    NotFound(String),
+
+    #[error("{0}")]
+    InvalidArgument(String),
+}
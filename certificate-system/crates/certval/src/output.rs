@@ -0,0 +1,123 @@
+//! Rendering of command results as human-readable tables or JSON
+//!
+//! Commands build a `serde_json::Value`: an object is printed as one `key: value`
+//! line per field, an array of objects as a table with one column per key.
+
+use serde_json::{Map, Value};
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+}
+
+/// Print a command result in the chosen format
+pub fn print(format: OutputFormat, value: &Value) {
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(value).expect("JSON value serializes"))
+        }
+        OutputFormat::Table => print!("{}", render(value, 0)),
+    }
+}
+
+fn render(value: &Value, indent: usize) -> String {
+    match value {
+        Value::Object(fields) => render_object(fields, indent),
+        Value::Array(rows) if rows.iter().all(Value::is_object) => render_table(rows, indent),
+        other => format!("{}{}\n", pad(indent), cell(other)),
+    }
+}
+
+fn render_object(fields: &Map<String, Value>, indent: usize) -> String {
+    let width = fields.keys().map(String::len).max().unwrap_or(0);
+    let mut out = String::new();
+    for (key, value) in fields {
+        if is_nested(value) {
+            out.push_str(&format!("{}{key}:\n", pad(indent)));
+            out.push_str(&render(value, indent + 2));
+        } else {
+            out.push_str(&format!("{}{key:<width$}  {}\n", pad(indent), cell(value)));
+        }
+    }
+    out
+}
+
+fn render_table(rows: &[Value], indent: usize) -> String {
+    if rows.is_empty() {
+        return format!("{}(none)\n", pad(indent));
+    }
+
+    // Columns in order of first appearance across all rows
+    let mut columns: Vec<&str> = Vec::new();
+    for row in rows.iter().filter_map(Value::as_object) {
+        for key in row.keys() {
+            if !columns.contains(&key.as_str()) {
+                columns.push(key);
+            }
+        }
+    }
+
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| row.get(*column).map_or_else(|| cell(&Value::Null), cell))
+                .collect()
+        })
+        .collect();
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            cells
+                .iter()
+                .map(|row| row[i].chars().count())
+                .chain([column.len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let line = |values: Vec<&str>| {
+        let joined = values
+            .iter()
+            .zip(&widths)
+            .map(|(value, width)| format!("{value:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        format!("{}{}\n", pad(indent), joined.trim_end())
+    };
+
+    let header: Vec<String> = columns.iter().map(|column| column.to_uppercase()).collect();
+    let mut out = line(header.iter().map(String::as_str).collect());
+    for row in &cells {
+        out.push_str(&line(row.iter().map(String::as_str).collect()));
+    }
+    out
+}
+
+/// Objects and arrays of objects get their own indented block
+fn is_nested(value: &Value) -> bool {
+    match value {
+        Value::Object(_) => true,
+        Value::Array(rows) => !rows.is_empty() && rows.iter().all(Value::is_object),
+        _ => false,
+    }
+}
+
+/// Single-line rendering of a scalar (or scalar list) cell
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(text) => text.clone(),
+        Value::Array(items) if items.is_empty() => "-".to_string(),
+        Value::Array(items) => items.iter().map(cell).collect::<Vec<_>>().join(", "),
+        other => other.to_string(),
+    }
+}
+
+fn pad(indent: usize) -> String {
+    " ".repeat(indent)
+}
@@ -0,0 +1,203 @@
+//! `certval registry`: set up and inspect the institute registry
+
+use crate::{pubkey_value, CliError, Context};
+use cert_val_client::institute_validator::{
+    GovernanceConfig, InstituteEntry, InstituteRegistry, InstituteStatus, RemovedIssuerPolicy,
+    VotingState,
+};
+use cert_val_client::{
+    find_governance_config_pda, find_institute_registry_pda, GovernanceParams,
+    INSTITUTE_VALIDATOR_ID,
+};
+use clap::{Args, Subcommand, ValueEnum};
+use serde_json::{json, Map, Value};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use std::process::ExitCode;
+
+#[derive(Subcommand)]
+pub enum RegistryCommand {
+    /// Create the registry with its founding institutes; the keypair becomes its authority
+    Init {
+        /// Founding institutes
+        #[arg(required = true)]
+        institutes: Vec<Pubkey>,
+
+        #[command(flatten)]
+        governance: GovernanceArgs,
+    },
+    /// Show the registry, its governance parameters and its institutes
+    Show,
+}
+
+/// Governance parameters; unset values keep the current (or default) parameters
+#[derive(Args)]
+pub struct GovernanceArgs {
+    /// Share of votes cast that must be in favour, in basis points [default: 5001]
+    #[arg(long)]
+    pub approval_threshold_bps: Option<u16>,
+
+    /// Share of eligible voters that must cast a vote, in basis points [default: 5000]
+    #[arg(long)]
+    pub quorum_bps: Option<u16>,
+
+    /// Shortest voting period, in seconds [default: 3600]
+    #[arg(long)]
+    pub min_voting_period: Option<i64>,
+
+    /// How verifiers treat certificates of removed institutes [default: grandfather]
+    #[arg(long, value_enum)]
+    pub removed_issuer_policy: Option<PolicyArg>,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+pub enum PolicyArg {
+    Grandfather,
+    FlagAll,
+}
+
+impl GovernanceArgs {
+    /// Whether any parameter was given
+    pub fn is_set(&self) -> bool {
+        self.approval_threshold_bps.is_some()
+            || self.quorum_bps.is_some()
+            || self.min_voting_period.is_some()
+            || self.removed_issuer_policy.is_some()
+    }
+
+    /// Resolve the parameters, falling back to `current` and then to the defaults
+    pub fn params(&self, current: Option<&GovernanceConfig>) -> GovernanceParams {
+        GovernanceParams {
+            approval_threshold_bps: self
+                .approval_threshold_bps
+                .or(current.map(|config| config.approval_threshold_bps))
+                .unwrap_or(5_001),
+            quorum_bps: self
+                .quorum_bps
+                .or(current.map(|config| config.quorum_bps))
+                .unwrap_or(5_000),
+            min_voting_period: self
+                .min_voting_period
+                .or(current.map(|config| config.min_voting_period))
+                .unwrap_or(VotingState::MIN_VOTING_PERIOD),
+            removed_issuer_policy: match self.removed_issuer_policy {
+                Some(PolicyArg::Grandfather) => RemovedIssuerPolicy::Grandfather,
+                Some(PolicyArg::FlagAll) => RemovedIssuerPolicy::FlagAll,
+                None => current.map_or(RemovedIssuerPolicy::Grandfather, |config| {
+                    config.removed_issuer_policy.clone()
+                }),
+            },
+        }
+    }
+}
+
+pub fn run(context: &Context, command: RegistryCommand) -> Result<ExitCode, CliError> {
+    match command {
+        RegistryCommand::Init {
+            institutes,
+            governance,
+        } => init(context, institutes, governance),
+        RegistryCommand::Show => show(context),
+    }
+}
+
+fn init(
+    context: &Context,
+    institutes: Vec<Pubkey>,
+    governance: GovernanceArgs,
+) -> Result<ExitCode, CliError> {
+    let authority = context.signer()?;
+    let instruction = cert_val_client::initialize_registry(
+        &authority.pubkey(),
+        institutes.clone(),
+        governance.params(None),
+    );
+    let signature = context.client.send(&[instruction], &authority, &[])?;
+
+    let mut fields = Map::new();
+    fields.insert("registry".into(), pubkey_value(&find_institute_registry_pda().0));
+    fields.insert("authority".into(), pubkey_value(&authority.pubkey()));
+    fields.insert("institutes".into(), institutes.iter().map(pubkey_value).collect());
+    context.print_signature(signature, fields);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn show(context: &Context) -> Result<ExitCode, CliError> {
+    let registry = context
+        .client
+        .fetch_registry()?
+        .ok_or_else(|| CliError::NotFound("registry is not initialized".to_string()))?;
+    let governance = context.client.fetch_governance_config()?;
+    let mut institutes = context
+        .client
+        .fetch_all::<InstituteEntry>(&INSTITUTE_VALIDATOR_ID)?;
+    institutes.sort_by_key(|(_, entry)| (entry.status != InstituteStatus::Active, entry.joined_at));
+
+    context.print(&json!({
+        "address": find_institute_registry_pda().0.to_string(),
+        "institute_count": registry.institute_count,
+        "open_admissions": registry.open_admissions,
+        "authorities": registry.authorities.iter().map(pubkey_value).collect::<Vec<_>>(),
+        "authority_threshold": registry.authority_threshold,
+        "pending_transfer": registry.pending_transfer.as_ref().map(|transfer| json!({
+            "authorities": transfer.authorities.iter().map(pubkey_value).collect::<Vec<_>>(),
+            "threshold": transfer.threshold,
+            "proposed_at": transfer.proposed_at,
+        })),
+        "guardians": registry.guardians.iter().map(pubkey_value).collect::<Vec<_>>(),
+        "paused_scopes": paused_scopes(registry.paused_scopes),
+        "governance": governance.as_ref().map(|config| json!({
+            "address": find_governance_config_pda().0.to_string(),
+            "approval_threshold_bps": config.approval_threshold_bps,
+            "quorum_bps": config.quorum_bps,
+            "min_voting_period": config.min_voting_period,
+            "removed_issuer_policy": policy_name(&config.removed_issuer_policy),
+            "updated_at": config.updated_at,
+        })),
+        "institutes": institutes.iter().map(|(_, entry)| json!({
+            "institute": entry.institute.to_string(),
+            "signing_key": entry.signing_key.to_string(),
+            "status": status_name(&entry.status),
+            "joined_at": entry.joined_at,
+            "removed_at": entry.removed_at,
+        })).collect::<Vec<_>>(),
+    }));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn paused_scopes(flags: u8) -> Vec<&'static str> {
+    [
+        (InstituteRegistry::PAUSE_ISSUANCE, "issuance"),
+        (InstituteRegistry::PAUSE_REVOCATION, "revocation"),
+        (InstituteRegistry::PAUSE_GOVERNANCE, "governance"),
+        (InstituteRegistry::PAUSE_VERIFICATION, "verification"),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| flags & flag != 0)
+    .map(|(_, name)| name)
+    .collect()
+}
+
+pub fn policy_name(policy: &RemovedIssuerPolicy) -> &'static str {
+    match policy {
+        RemovedIssuerPolicy::Grandfather => "grandfather",
+        RemovedIssuerPolicy::FlagAll => "flag-all",
+    }
+}
+
+pub fn status_name(status: &InstituteStatus) -> &'static str {
+    match status {
+        InstituteStatus::Active => "active",
+        InstituteStatus::Removed => "removed",
+    }
+}
+
+/// Governance parameters as a nested output value
+pub fn governance_value(params: &GovernanceParams) -> Value {
+    json!({
+        "approval_threshold_bps": params.approval_threshold_bps,
+        "quorum_bps": params.quorum_bps,
+        "min_voting_period": params.min_voting_period,
+        "removed_issuer_policy": policy_name(&params.removed_issuer_policy),
+    })
+}
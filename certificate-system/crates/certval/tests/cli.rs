@@ -0,0 +1,63 @@
+use std::process::{Command, Output};
+
+fn certval(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_certval"))
+        .args(["--keypair", "/nonexistent/id.json", "--url", "http://127.0.0.1:1"])
+        .args(args)
+        .output()
+        .expect("certval runs")
+}
+
+#[test]
+fn certificate_source_is_required_and_exclusive() {
+    let hash = "e277fa489cc37176923f945a502ad466c241cd9daf4b97a65845e9d3ef3f4196";
+
+    let output = certval(&["certificate", "verify"]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = certval(&["certificate", "verify", "--hash", hash, "--file", "Cargo.toml"]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = certval(&["certificate", "verify", "--hash", &hash[2..]]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("expected 64 hex characters"));
+}
+
+#[test]
+fn documents_are_hashed_before_signing() {
+    let dir = std::env::temp_dir().join(format!("certval-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let document = dir.join("document.json");
+    std::fs::write(
+        &document,
+        r#"{"holder_name":"Asha Verma","holder_id":"CS-2021-042",
+            "credential_title":"Bachelor of Science","issued_on":"2025-02-30"}"#,
+    )
+    .unwrap();
+
+    let output = certval(&["certificate", "verify", "--document", document.to_str().unwrap()]);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid date `2025-02-30`"));
+}
+
+#[test]
+fn governance_elections_take_no_subject() {
+    let subject = "11111111111111111111111111111111";
+    let output = certval(&["election", "vote", "governance", subject, "--for"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("take no subject"));
+}
+
+#[test]
+fn filter_check_takes_one_certificate_source() {
+    let output = certval(&["filter", "check"]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let hash = "e277fa489cc37176923f945a502ad466c241cd9daf4b97a65845e9d3ef3f4196";
+    let output = certval(&["filter", "check", "--hash", hash]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("rpc error"));
+}
@@ -1,4 +1,10 @@
 use anchor_lang::prelude::*;
+use institute_validator::program::InstituteValidator;
+use institute_validator::{
+    create_pda_account, GovernanceConfig, InstituteEntry, InstituteKey, InstituteProfile,
+    InstituteRegistry, InstituteStatus, RemovedIssuerPolicy,
+};
+use solana_sha256_hasher::hashv;
 
 declare_id!("BkxAccdVywyovJuU5RqdR1jHpWT7z6wfgZc9akEdSpqE");
 
@@ -7,51 +13,83 @@ pub mod certificate_system {
     use super::*;
 
     /// Adds a new certificate to the registry
-    /// Validates that the issuer exists in the InstituteRegistry
+    /// Validates that the issuer has an active entry in the InstituteRegistry
+    /// The certificate records the institute identity as issuer, and the signing key
+    /// (the institute's own key or a delegate) that performed the issuance
+    /// An optional expiry can be set for certificates that lapse (licenses, trainings)
+    /// An optional holder binds the certificate to a wallet, or to a salted commitment
+    /// to one so that the holder stays private until they prove holdership
+    /// Pass a CredentialSchema account of the issuing institute to record the schema the
+    /// hash was computed under
+    /// The certificate receives the next issuer-local serial and is indexed under it in a
+    /// CertificateIndex PDA; the institute's IssuerStats is created with its first certificate
+    /// The hash is inserted into the current CertificateFilter generation, once there is one
     pub fn add_certificate(
         ctx: Context<AddCertificate>,
         certificate_hash: [u8; 32],
+        expires_at: Option<i64>,
+        holder: Option<CertificateHolder>,
     ) -> Result<()> {
         let certificate = &mut ctx.accounts.certificate;
-        let issuer = ctx.accounts.issuer.key();
+        let signer = ctx.accounts.issuer.key();
+        let now = Clock::get()?.unix_timestamp;
 
-        // Verify the institute_registry PDA
-        let (expected_pda, _bump) = Pubkey::find_program_address(
-            &[b"institute_registry"],
-            ctx.accounts.institute_validator_program.key,
-        );
-        require!(
-            ctx.accounts.institute_registry.key() == expected_pda,
-            CertificateError::InvalidInstituteRegistry
-        );
-
-        // Verify the account is owned by the institute_validator_program
-        require!(
-            ctx.accounts.institute_registry.owner == ctx.accounts.institute_validator_program.key,
-            CertificateError::InvalidInstituteRegistry
-        );
+        // Expiry, if any, must lie in the future
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > now, CertificateError::InvalidExpiry);
+        }
 
-        // Deserialize and validate the InstituteRegistry account
-        let institute_registry = InstituteRegistry::try_deserialize(
-            &mut &ctx.accounts.institute_registry.data.borrow()[..]
+        // Validate that the signing key belongs to an institute in the registry
+        let issuer = require_registered_issuer(
+            &ctx.accounts.issuer_key,
+            &ctx.accounts.issuer_entry,
+            InstituteKey::PERMISSION_ISSUE,
+            now,
         )?;
 
-        // Validate that the issuer exists in the InstituteRegistry
-        require!(
-            institute_registry.is_institute_registered(&issuer),
-            CertificateError::IssuerNotRegistered
-        );
+        // Schemas are registered per institute: only the issuer's own can be cited
+        if let Some(schema) = &ctx.accounts.credential_schema {
+            require_keys_eq!(
+                schema.institute,
+                issuer,
+                CertificateError::InvalidCredentialSchema
+            );
+        }
+
+        // Assign the next issuer-local serial and index the certificate under it
+        let serial = index_certificate(
+            &issuer,
+            certificate_hash,
+            |stats| &mut stats.issued_count,
+            &ctx.accounts.issuer_stats,
+            &ctx.accounts.certificate_index,
+            &ctx.accounts.issuer,
+            &ctx.accounts.system_program,
+        )?;
+        record_in_filter(
+            &ctx.accounts.filter_state,
+            ctx.accounts.certificate_filter.as_ref(),
+            &certificate_hash,
+        )?;
 
         // Initialize certificate data
         certificate.certificate_hash = certificate_hash;
         certificate.issuer = issuer;
+        certificate.issued_by = signer;
+        certificate.serial = serial;
+        certificate.holder = holder;
+        certificate.schema = ctx.accounts.credential_schema.as_ref().map(|schema| schema.key());
         certificate.is_valid = true;
-        certificate.issued_at = Clock::get()?.unix_timestamp;
+        certificate.issued_at = now;
+        certificate.expires_at = expires_at;
+        certificate.version = 1;
         certificate.bump = ctx.bumps.certificate;
 
         emit!(CertificateAdded {
             certificate_hash,
             issuer,
+            serial,
+            signed_by: signer,
             timestamp: certificate.issued_at,
         });
 
@@ -59,6 +97,10 @@ pub mod certificate_system {
     }
 
     /// Corrects an existing certificate by marking it invalid and creating a new one
+    /// The replacement inherits the expiry, holder and schema of the corrected certificate,
+    /// links back to it through `previous_hash` and takes the next version number
+    /// The replacement is indexed under the issuer's next serial like a new issuance, and
+    /// inserted into the current CertificateFilter generation, once there is one
     pub fn correct_certificate(
         ctx: Context<CorrectCertificate>,
         old_hash: [u8; 32],
@@ -66,7 +108,15 @@ pub mod certificate_system {
     ) -> Result<()> {
         let old_certificate = &mut ctx.accounts.old_certificate_pda;
         let new_certificate = &mut ctx.accounts.new_certificate;
-        let issuer = ctx.accounts.issuer.key();
+        let signer = ctx.accounts.issuer.key();
+
+        // Validate that the signing key belongs to an institute that is still registered
+        let issuer = require_registered_issuer(
+            &ctx.accounts.issuer_key,
+            &ctx.accounts.issuer_entry,
+            InstituteKey::PERMISSION_CORRECT,
+            Clock::get()?.unix_timestamp,
+        )?;
 
         // Verify the old certificate belongs to this issuer
         require!(
@@ -80,32 +130,22 @@ pub mod certificate_system {
             CertificateError::InvalidCertificateHash
         );
 
-        // Verify the institute_registry PDA
-        let (expected_pda, _bump) = Pubkey::find_program_address(
-            &[b"institute_registry"],
-            ctx.accounts.institute_validator_program.key,
-        );
-        require!(
-            ctx.accounts.institute_registry.key() == expected_pda,
-            CertificateError::InvalidInstituteRegistry
-        );
-
-        // Verify the account is owned by the institute_validator_program
-        require!(
-            ctx.accounts.institute_registry.owner == ctx.accounts.institute_validator_program.key,
-            CertificateError::InvalidInstituteRegistry
-        );
+        let version = old_certificate.version + 1;
 
-        // Deserialize and validate the InstituteRegistry account
-        let institute_registry = InstituteRegistry::try_deserialize(
-            &mut &ctx.accounts.institute_registry.data.borrow()[..]
+        let serial = index_certificate(
+            &issuer,
+            new_hash,
+            |stats| &mut stats.corrected_count,
+            &ctx.accounts.issuer_stats,
+            &ctx.accounts.certificate_index,
+            &ctx.accounts.issuer,
+            &ctx.accounts.system_program,
+        )?;
+        record_in_filter(
+            &ctx.accounts.filter_state,
+            ctx.accounts.certificate_filter.as_ref(),
+            &new_hash,
         )?;
-
-        // Validate that the issuer is still registered
-        require!(
-            institute_registry.is_institute_registered(&issuer),
-            CertificateError::IssuerNotRegistered
-        );
 
         // Mark old certificate as invalid
         old_certificate.is_valid = false;
@@ -115,36 +155,689 @@ pub mod certificate_system {
         // Initialize new certificate
         new_certificate.certificate_hash = new_hash;
         new_certificate.issuer = issuer;
+        new_certificate.issued_by = signer;
+        new_certificate.serial = serial;
+        new_certificate.holder = old_certificate.holder.clone();
+        new_certificate.schema = old_certificate.schema;
         new_certificate.is_valid = true;
         new_certificate.issued_at = Clock::get()?.unix_timestamp;
+        new_certificate.expires_at = old_certificate.expires_at;
+        new_certificate.renewal_count = 0;
         new_certificate.corrected_at = None;
         new_certificate.replacement_hash = None;
+        new_certificate.previous_hash = Some(old_hash);
+        new_certificate.version = version;
+        new_certificate.revoked_at = None;
+        new_certificate.revocation_reason = None;
+        new_certificate.revocation_note_hash = None;
+        new_certificate.revoked_by = None;
         new_certificate.bump = ctx.bumps.new_certificate;
 
         emit!(CertificateCorrected {
             old_hash,
             new_hash,
+            version,
+            serial,
             issuer,
+            signed_by: signer,
             timestamp: new_certificate.issued_at,
         });
 
         Ok(())
     }
 
+    /// Revokes a certificate without issuing a replacement
+    /// Only the original issuer can revoke, and only while the certificate is still valid
+    pub fn revoke_certificate(
+        ctx: Context<RevokeCertificate>,
+        certificate_hash: [u8; 32],
+        reason: RevocationReason,
+        note_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let certificate = &mut ctx.accounts.certificate;
+        let signer = ctx.accounts.issuer.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        // Resolve the signing key to its institute
+        let issuer = resolve_issuer_key(
+            &ctx.accounts.issuer_key,
+            InstituteKey::PERMISSION_REVOKE,
+            now,
+        )?;
+
+        // Verify the certificate belongs to this issuer
+        require!(
+            certificate.issuer == issuer,
+            CertificateError::UnauthorizedIssuer
+        );
+
+        // Mark certificate as revoked
+        certificate.is_valid = false;
+        certificate.revoked_at = Some(now);
+        certificate.revocation_reason = Some(reason.clone());
+        certificate.revocation_note_hash = note_hash;
+        certificate.revoked_by = Some(signer);
+
+        // Counted in the institute's IssuerStats, created here for certificates issued before it
+        let issuer_stats = &ctx.accounts.issuer_stats;
+        let mut stats = load_issuer_stats(
+            &issuer,
+            issuer_stats,
+            &ctx.accounts.issuer,
+            &ctx.accounts.system_program,
+        )?;
+        stats.revoked_count += 1;
+        stats.try_serialize(&mut &mut issuer_stats.data.borrow_mut()[..])?;
+
+        emit!(CertificateRevoked {
+            certificate_hash,
+            issuer,
+            signed_by: signer,
+            reason,
+            note_hash,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Extends the expiry of a certificate and records the renewal
+    /// Each renewal is kept in its own CertificateRenewal PDA to preserve the history
+    pub fn extend_certificate_expiry(
+        ctx: Context<ExtendCertificateExpiry>,
+        certificate_hash: [u8; 32],
+        new_expires_at: i64,
+    ) -> Result<()> {
+        let certificate = &mut ctx.accounts.certificate;
+        let renewal = &mut ctx.accounts.renewal;
+        let signer = ctx.accounts.issuer.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        // Validate that the signing key belongs to an institute that is still registered
+        let issuer = require_registered_issuer(
+            &ctx.accounts.issuer_key,
+            &ctx.accounts.issuer_entry,
+            InstituteKey::PERMISSION_CORRECT,
+            now,
+        )?;
+
+        // Verify the certificate belongs to this issuer
+        require!(
+            certificate.issuer == issuer,
+            CertificateError::UnauthorizedIssuer
+        );
+
+        // Only certificates issued with an expiry can be renewed
+        let previous_expires_at = certificate
+            .expires_at
+            .ok_or(CertificateError::CertificateDoesNotExpire)?;
+
+        // New expiry must extend the current one and lie in the future
+        require!(
+            new_expires_at > previous_expires_at && new_expires_at > now,
+            CertificateError::InvalidExpiry
+        );
+
+        // Record the renewal
+        renewal.certificate_hash = certificate_hash;
+        renewal.sequence = certificate.renewal_count;
+        renewal.previous_expires_at = previous_expires_at;
+        renewal.new_expires_at = new_expires_at;
+        renewal.renewed_at = now;
+        renewal.renewed_by = signer;
+        renewal.bump = ctx.bumps.renewal;
+
+        // Update certificate
+        certificate.expires_at = Some(new_expires_at);
+        certificate.renewal_count = certificate
+            .renewal_count
+            .checked_add(1)
+            .ok_or(CertificateError::RenewalLimitReached)?;
+
+        emit!(CertificateRenewed {
+            certificate_hash,
+            issuer,
+            signed_by: signer,
+            previous_expires_at,
+            new_expires_at,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
     /// View function to verify certificate status
+    /// The derived state accounts for corrections, revocations and expiry against the Clock,
+    /// and for the issuer's registry standing under the governance RemovedIssuerPolicy
+    /// Pass the issuer's InstituteProfile PDA to resolve issuer profile data, and the
+    /// certificate's CredentialSchema to learn how to recompute the hash from the document
     /// This is primarily for demonstration; clients typically read PDA directly via RPC
     pub fn verify_certificate(ctx: Context<VerifyCertificate>) -> Result<CertificateStatus> {
         let certificate = &ctx.accounts.certificate;
+        let standing =
+            IssuerStanding::new(&ctx.accounts.issuer_entry, &ctx.accounts.governance_config);
+        let state = standing.apply(
+            certificate.state(Clock::get()?.unix_timestamp),
+            certificate.issued_at,
+        );
+        let issuer_profile = ctx.accounts.issuer_profile.as_deref().map(IssuerProfile::from);
+        let credential_schema = ctx
+            .accounts
+            .credential_schema
+            .as_deref()
+            .map(CredentialSchemaInfo::from);
 
         Ok(CertificateStatus {
             certificate_hash: certificate.certificate_hash,
             issuer: certificate.issuer,
-            is_valid: certificate.is_valid,
+            issued_by: certificate.issued_by,
+            holder: certificate.holder.clone(),
+            schema: certificate.schema,
+            is_valid: state == CertificateState::Valid,
+            state,
             issued_at: certificate.issued_at,
+            expires_at: certificate.expires_at,
+            renewal_count: certificate.renewal_count,
             corrected_at: certificate.corrected_at,
             replacement_hash: certificate.replacement_hash,
+            previous_hash: certificate.previous_hash,
+            version: certificate.version,
+            revoked_at: certificate.revoked_at,
+            revocation_reason: certificate.revocation_reason.clone(),
+            revocation_note_hash: certificate.revocation_note_hash,
+            revoked_by: certificate.revoked_by,
+            issuer_status: standing.status,
+            issuer_removed_at: standing.removed_at,
+            issuer_profile,
+            credential_schema,
+        })
+    }
+
+    /// Gate for other programs: fails unless the certificate is currently valid
+    /// (not corrected, revoked or expired) and its issuer is still a registered institute
+    /// Pass `expected_issuer` to also require a specific issuing institute, and
+    /// `expected_schema` to require a credential type (CredentialSchema address)
+    /// Third-party programs call it through `certificate_system::cpi::require_valid_certificate`
+    /// (enable the `cpi` feature); a failed check aborts the calling transaction
+    pub fn require_valid_certificate(
+        ctx: Context<RequireValidCertificate>,
+        expected_issuer: Option<Pubkey>,
+        expected_schema: Option<Pubkey>,
+    ) -> Result<()> {
+        let certificate = &ctx.accounts.certificate;
+
+        match certificate.state(Clock::get()?.unix_timestamp) {
+            CertificateState::Valid => {}
+            CertificateState::Expired => return err!(CertificateError::CertificateExpired),
+            _ => return err!(CertificateError::CertificateNotValid),
+        }
+
+        require!(
+            ctx.accounts.issuer_entry.is_active(),
+            CertificateError::IssuerNotRegistered
+        );
+
+        if let Some(expected_issuer) = expected_issuer {
+            require!(
+                certificate.issuer == expected_issuer,
+                CertificateError::UnexpectedIssuer
+            );
+        }
+
+        if let Some(expected_schema) = expected_schema {
+            require!(
+                certificate.schema == Some(expected_schema),
+                CertificateError::UnexpectedSchema
+            );
+        }
+
+        Ok(())
+    }
+
+    /// View function returning the full correction history of a certificate
+    /// Pass every certificate of the chain as remaining accounts, ordered from the original
+    /// (version 1) to the current head; the queried certificate may be any of them
+    /// Every link is checked in both directions, so the result is the complete chain
+    /// Entry states ignore the issuer's registry standing; call verify_certificate on the
+    /// head for that
+    pub fn get_certificate_lineage(
+        ctx: Context<GetCertificateLineage>,
+    ) -> Result<CertificateLineage> {
+        let certificate = &ctx.accounts.certificate;
+        let chain = ctx.remaining_accounts;
+        require!(
+            chain.len() <= Certificate::MAX_LINEAGE_DEPTH as usize,
+            CertificateError::LineageTooDeep
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let mut entries: Vec<LineageEntry> = Vec::with_capacity(chain.len());
+        let mut replacement_hash = None;
+        for (index, info) in chain.iter().enumerate() {
+            let link = load_certificate(info)?;
+
+            // Each version must point back to its predecessor, and the predecessor forward to it
+            let previous_hash = entries.last().map(|entry| entry.certificate_hash);
+            require!(
+                link.version as usize == index + 1
+                    && link.previous_hash == previous_hash
+                    && (index == 0 || replacement_hash == Some(link.certificate_hash)),
+                CertificateError::InvalidLineage
+            );
+            replacement_hash = link.replacement_hash;
+
+            entries.push(LineageEntry {
+                certificate_hash: link.certificate_hash,
+                version: link.version,
+                issued_at: link.issued_at,
+                corrected_at: link.corrected_at,
+                state: link.state(now),
+            });
+        }
+
+        // The chain must end at the head and contain the queried certificate
+        let position = certificate.version.saturating_sub(1);
+        require!(
+            replacement_hash.is_none()
+                && entries
+                    .get(position as usize)
+                    .is_some_and(|entry| entry.certificate_hash == certificate.certificate_hash),
+            CertificateError::InvalidLineage
+        );
+
+        Ok(CertificateLineage {
+            head: entries[entries.len() - 1].certificate_hash,
+            position,
+            entries,
+        })
+    }
+
+    /// View function returning the certificate an institute issued under `serial`
+    /// Serials run from 0 up to the institute's `IssuerStats::next_serial`, so an
+    /// institute's certificates can be paged without scanning program accounts
+    pub fn get_issuer_certificate(
+        ctx: Context<GetIssuerCertificate>,
+        serial: u64,
+    ) -> Result<Certificate> {
+        let certificate = &ctx.accounts.certificate;
+        require!(
+            certificate.issuer == ctx.accounts.issuer_stats.issuer && certificate.serial == serial,
+            CertificateError::InvalidIssuerIndex
+        );
+        Ok((**certificate).clone())
+    }
+
+    /// Registers a credential schema (BSc transcript, marksheet, course badge) for an institute
+    /// The schema is identified by its PDA address, derived from the institute, name and version
+    /// Schemas are immutable; changes to the field list are published as a new version
+    pub fn register_credential_schema(
+        ctx: Context<RegisterCredentialSchema>,
+        institute: Pubkey,
+        params: CredentialSchemaParams,
+    ) -> Result<()> {
+        let schema = &mut ctx.accounts.credential_schema;
+        let signer = ctx.accounts.issuer.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        params.validate()?;
+
+        // Validate that the signing key belongs to the registered institute
+        let issuer = require_registered_issuer(
+            &ctx.accounts.issuer_key,
+            &ctx.accounts.issuer_entry,
+            InstituteKey::PERMISSION_ISSUE,
+            now,
+        )?;
+        require!(issuer == institute, CertificateError::UnauthorizedIssuer);
+
+        schema.institute = institute;
+        schema.name = params.name;
+        schema.version = params.version;
+        schema.field_list_hash = params.field_list_hash;
+        schema.hashing_rule = params.hashing_rule;
+        schema.registered_by = signer;
+        schema.registered_at = now;
+        schema.bump = ctx.bumps.credential_schema;
+
+        emit!(CredentialSchemaRegistered {
+            schema: schema.key(),
+            institute,
+            name: schema.name.clone(),
+            version: schema.version,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Proves that the signer is the holder of a valid certificate
+    /// The verifier hands the holder a fresh `challenge`; the resulting HolderClaim PDA,
+    /// derived from the certificate hash and that challenge, is the verifiable claim
+    /// A commitment-bound holder reveals the salt of their commitment, linking the
+    /// certificate to their wallet but never to their name
+    pub fn prove_holdership(
+        ctx: Context<ProveHoldership>,
+        certificate_hash: [u8; 32],
+        challenge: [u8; 32],
+        salt: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let certificate = &ctx.accounts.certificate;
+        let claim = &mut ctx.accounts.holder_claim;
+        let holder = ctx.accounts.holder.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        // Only a currently valid certificate can be claimed
+        require!(
+            certificate.state(now) == CertificateState::Valid,
+            CertificateError::CertificateNotValid
+        );
+
+        // The signer must match the holder bound at issuance
+        let is_holder = match certificate
+            .holder
+            .as_ref()
+            .ok_or(CertificateError::HolderNotBound)?
+        {
+            CertificateHolder::Wallet(wallet) => *wallet == holder,
+            CertificateHolder::Commitment(commitment) => {
+                let salt = salt.ok_or(CertificateError::MissingHolderSalt)?;
+                *commitment == holder_commitment(&holder, &salt)
+            }
+        };
+        require!(is_holder, CertificateError::HolderMismatch);
+
+        // Record the claim
+        claim.certificate_hash = certificate_hash;
+        claim.holder = holder;
+        claim.challenge = challenge;
+        claim.claimed_at = now;
+        claim.bump = ctx.bumps.holder_claim;
+
+        emit!(HoldershipProven {
+            certificate_hash,
+            holder,
+            challenge,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Anchors a Merkle root of many certificate hashes under one issuer
+    /// The batch PDA is derived from the issuing institute and the root
+    /// Individual certificates are later proven with `verify_in_batch`
+    pub fn add_certificate_batch(
+        ctx: Context<AddCertificateBatch>,
+        merkle_root: [u8; 32],
+        leaf_count: u32,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let batch_info = &ctx.accounts.batch;
+        let signer = ctx.accounts.issuer.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            leaf_count > 0 && leaf_count <= CertificateBatch::MAX_LEAVES,
+            CertificateError::InvalidBatchSize
+        );
+
+        // Expiry, if any, must lie in the future
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > now, CertificateError::InvalidExpiry);
+        }
+
+        // Validate that the signing key belongs to an institute in the registry
+        let issuer = require_registered_issuer(
+            &ctx.accounts.issuer_key,
+            &ctx.accounts.issuer_entry,
+            InstituteKey::PERMISSION_ISSUE,
+            now,
+        )?;
+
+        // Batches are namespaced by issuer, so institutes cannot claim each other's roots
+        let (batch_pda, bump) = Pubkey::find_program_address(
+            &[b"certificate_batch", issuer.as_ref(), merkle_root.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(batch_info.key(), batch_pda, CertificateError::InvalidBatchAccount);
+        create_pda_account(
+            batch_info,
+            CertificateBatch::space(leaf_count),
+            &crate::ID,
+            &[b"certificate_batch", issuer.as_ref(), merkle_root.as_ref(), &[bump]],
+            &ctx.accounts.issuer,
+            &ctx.accounts.system_program,
+        )?;
+
+        // Initialize batch data
+        let batch = CertificateBatch {
+            merkle_root,
+            issuer,
+            issued_by: signer,
+            leaf_count,
+            issued_at: now,
+            expires_at,
+            revoked_count: 0,
+            revocation_bitmap: vec![0; CertificateBatch::bitmap_len(leaf_count)],
+            bump,
+        };
+        batch.try_serialize(&mut &mut batch_info.data.borrow_mut()[..])?;
+
+        emit!(CertificateBatchAdded {
+            merkle_root,
+            issuer,
+            signed_by: signer,
+            leaf_count,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Revokes a single certificate inside a batch
+    /// The leaf must be proven against the batch root so the revocation targets a real certificate
+    pub fn revoke_batch_leaf(
+        ctx: Context<RevokeBatchLeaf>,
+        leaf_hash: [u8; 32],
+        leaf_index: u32,
+        proof: Vec<[u8; 32]>,
+        reason: RevocationReason,
+        note_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let batch = &mut ctx.accounts.batch;
+        let revocation = &mut ctx.accounts.revocation;
+        let signer = ctx.accounts.issuer.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        // Resolve the signing key to its institute
+        let issuer = resolve_issuer_key(
+            &ctx.accounts.issuer_key,
+            InstituteKey::PERMISSION_REVOKE,
+            now,
+        )?;
+
+        // Verify the batch belongs to this issuer
+        require!(
+            batch.issuer == issuer,
+            CertificateError::UnauthorizedIssuer
+        );
+
+        // Verify the leaf is part of the batch
+        require!(
+            batch.contains(&leaf_hash, leaf_index, &proof),
+            CertificateError::InvalidMerkleProof
+        );
+
+        require!(
+            !batch.is_revoked(leaf_index),
+            CertificateError::CertificateAlreadyInvalid
+        );
+
+        // Mark leaf as revoked
+        batch.set_revoked(leaf_index);
+        batch.revoked_count += 1;
+
+        // Counted in the institute's IssuerStats like individual revocations
+        let issuer_stats = &ctx.accounts.issuer_stats;
+        let mut stats = load_issuer_stats(
+            &issuer,
+            issuer_stats,
+            &ctx.accounts.issuer,
+            &ctx.accounts.system_program,
+        )?;
+        stats.revoked_count += 1;
+        stats.try_serialize(&mut &mut issuer_stats.data.borrow_mut()[..])?;
+
+        revocation.batch = batch.key();
+        revocation.leaf_hash = leaf_hash;
+        revocation.leaf_index = leaf_index;
+        revocation.reason = reason.clone();
+        revocation.note_hash = note_hash;
+        revocation.revoked_at = now;
+        revocation.revoked_by = signer;
+        revocation.bump = ctx.bumps.revocation;
+
+        emit!(CertificateRevoked {
+            certificate_hash: leaf_hash,
+            issuer,
+            signed_by: signer,
+            reason,
+            note_hash,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// View function to verify a certificate anchored in a batch
+    /// Pass the leaf's BatchLeafRevocation PDA to include revocation details
+    pub fn verify_in_batch(
+        ctx: Context<VerifyInBatch>,
+        leaf_hash: [u8; 32],
+        leaf_index: u32,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<CertificateStatus> {
+        let batch = &ctx.accounts.batch;
+
+        // Verify the leaf is part of the batch
+        require!(
+            batch.contains(&leaf_hash, leaf_index, &proof),
+            CertificateError::InvalidMerkleProof
+        );
+
+        let standing =
+            IssuerStanding::new(&ctx.accounts.issuer_entry, &ctx.accounts.governance_config);
+        let state = standing.apply(
+            batch.leaf_state(leaf_index, Clock::get()?.unix_timestamp),
+            batch.issued_at,
+        );
+        let revocation = ctx.accounts.revocation.as_ref();
+        let issuer_profile = ctx.accounts.issuer_profile.as_deref().map(IssuerProfile::from);
+
+        Ok(CertificateStatus {
+            certificate_hash: leaf_hash,
+            issuer: batch.issuer,
+            issued_by: batch.issued_by,
+            holder: None,
+            schema: None,
+            is_valid: state == CertificateState::Valid,
+            state,
+            issued_at: batch.issued_at,
+            expires_at: batch.expires_at,
+            renewal_count: 0,
+            corrected_at: None,
+            replacement_hash: None,
+            previous_hash: None,
+            version: 1,
+            revoked_at: revocation.map(|r| r.revoked_at),
+            revocation_reason: revocation.map(|r| r.reason.clone()),
+            revocation_note_hash: revocation.and_then(|r| r.note_hash),
+            revoked_by: revocation.map(|r| r.revoked_by),
+            issuer_status: standing.status,
+            issuer_removed_at: standing.removed_at,
+            issuer_profile,
+            credential_schema: None,
         })
     }
+
+    /// Creates the first CertificateFilter generation with the latest FilterParams
+    /// The filter is too large to be created via CPI: the caller creates it beforehand with a
+    /// system program `create_account` of CertificateFilter::LEN bytes owned by this program
+    /// Requires the threshold of registry authorities; additional authority signers are
+    /// passed as remaining accounts
+    pub fn initialize_certificate_filter(ctx: Context<InitializeCertificateFilter>) -> Result<()> {
+        require!(
+            ctx.accounts
+                .institute_registry
+                .is_authorized(ctx.accounts.authority.key, ctx.remaining_accounts),
+            CertificateError::AuthorityThresholdNotMet
+        );
+
+        let filter_state = &mut ctx.accounts.filter_state;
+        let filter_key = ctx.accounts.filter.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        let mut filter = ctx.accounts.filter.load_init()?;
+        filter.init(0, FilterParams::latest(), None, now);
+
+        filter_state.current = filter_key;
+        filter_state.generation = 0;
+        filter_state.rotated_at = now;
+        filter_state.bump = ctx.bumps.filter_state;
+
+        emit!(CertificateFilterCreated {
+            filter: filter_key,
+            generation: 0,
+            params_version: filter.params_version,
+            previous: None,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Starts a new CertificateFilter generation, created by the caller like the first one
+    /// Allowed once the current filter reaches the maximum fill rate of its parameters, or
+    /// when newer FilterParams exist; the rotated filter keeps its bits, so a certificate
+    /// hash is known to be absent only when it is absent from every generation
+    /// Requires the threshold of registry authorities, like the first generation
+    pub fn rotate_certificate_filter(ctx: Context<RotateCertificateFilter>) -> Result<()> {
+        require!(
+            ctx.accounts
+                .institute_registry
+                .is_authorized(ctx.accounts.authority.key, ctx.remaining_accounts),
+            CertificateError::AuthorityThresholdNotMet
+        );
+
+        let filter_state = &mut ctx.accounts.filter_state;
+        let filter_key = ctx.accounts.filter.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        let mut current = ctx.accounts.current_filter.load_mut()?;
+        require!(
+            current.is_full() || current.params_version < FilterParams::latest().version,
+            CertificateError::FilterNotFull
+        );
+        current.successor = filter_key;
+
+        let generation = filter_state.generation + 1;
+        let mut filter = ctx.accounts.filter.load_init()?;
+        filter.init(generation, FilterParams::latest(), Some(filter_state.current), now);
+
+        emit!(CertificateFilterCreated {
+            filter: filter_key,
+            generation,
+            params_version: filter.params_version,
+            previous: Some(filter_state.current),
+            timestamp: now,
+        });
+
+        filter_state.current = filter_key;
+        filter_state.generation = generation;
+        filter_state.rotated_at = now;
+
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -157,118 +850,1439 @@ pub struct Certificate {
     pub certificate_hash: [u8; 32],
     /// Public key of the issuing institute
     pub issuer: Pubkey,
+    /// Signing key that issued the certificate (institute key or delegate)
+    pub issued_by: Pubkey,
+    /// Issuer-local serial number, locating the certificate's CertificateIndex
+    pub serial: u64,
+    /// Holder the certificate was issued to (None if unbound)
+    pub holder: Option<CertificateHolder>,
+    /// CredentialSchema the certificate hash was computed under (None if unspecified)
+    pub schema: Option<Pubkey>,
     /// Validity status of the certificate
     pub is_valid: bool,
     /// Timestamp when certificate was issued
     pub issued_at: i64,
+    /// Timestamp after which the certificate is expired (None if it never expires)
+    pub expires_at: Option<i64>,
+    /// Number of expiry extensions recorded for this certificate
+    pub renewal_count: u16,
     /// Timestamp when certificate was corrected (if applicable)
     pub corrected_at: Option<i64>,
     /// Replacement certificate hash (if corrected)
     pub replacement_hash: Option<[u8; 32]>,
+    /// Hash of the certificate this one corrects (None for an original issuance)
+    pub previous_hash: Option<[u8; 32]>,
+    /// Position in the correction chain (1 for an original issuance)
+    pub version: u16,
+    /// Timestamp when certificate was revoked (if applicable)
+    pub revoked_at: Option<i64>,
+    /// Reason given by the issuer for the revocation
+    pub revocation_reason: Option<RevocationReason>,
+    /// Hash of an off-chain free-text revocation note
+    pub revocation_note_hash: Option<[u8; 32]>,
+    /// Signing key that revoked the certificate (if applicable)
+    pub revoked_by: Option<Pubkey>,
     /// PDA bump seed
     pub bump: u8,
 }
 
 impl Certificate {
+    /// Longest correction chain get_certificate_lineage returns, so that it fits in the
+    /// view's return data; corrections themselves are not bounded
+    pub const MAX_LINEAGE_DEPTH: u16 = 16;
+
     pub const LEN: usize = 8 + // discriminator
         32 + // certificate_hash
         32 + // issuer
+        32 + // issued_by
+        8 + // serial
+        1 + 1 + 32 + // holder (Option enum)
+        1 + 32 + // schema (Option)
         1 + // is_valid
         8 + // issued_at
+        1 + 8 + // expires_at (Option)
+        2 + // renewal_count
         1 + 8 + // corrected_at (Option)
         1 + 32 + // replacement_hash (Option)
+        1 + 32 + // previous_hash (Option)
+        2 + // version
+        1 + 8 + // revoked_at (Option)
+        1 + 1 + // revocation_reason (Option enum)
+        1 + 32 + // revocation_note_hash (Option)
+        1 + 32 + // revoked_by (Option)
         1; // bump
 }
 
-/// InstituteRegistry account (owned by InstituteValidator program)
-/// This is a cross-program account read for validation
+impl Certificate {
+    /// Derive the effective state of the certificate at the given time
+    pub fn state(&self, now: i64) -> CertificateState {
+        if self.revoked_at.is_some() {
+            CertificateState::Revoked
+        } else if self.replacement_hash.is_some() {
+            CertificateState::Corrected
+        } else if self.expires_at.is_some_and(|expires_at| now >= expires_at) {
+            CertificateState::Expired
+        } else {
+            CertificateState::Valid
+        }
+    }
+}
+
 #[account]
-pub struct InstituteRegistry {
-    /// List of registered institute public keys
-    pub registered_institutes: Vec<Pubkey>,
-    /// Authority that can modify the registry
-    pub authority: Pubkey,
-    /// Bump seed for PDA
+pub struct CertificateRenewal {
+    /// Hash of the renewed certificate
+    pub certificate_hash: [u8; 32],
+    /// Position of this renewal in the certificate's history (0-based)
+    pub sequence: u16,
+    /// Expiry before the renewal
+    pub previous_expires_at: i64,
+    /// Expiry after the renewal
+    pub new_expires_at: i64,
+    /// Timestamp when the renewal was recorded
+    pub renewed_at: i64,
+    /// Key that performed the renewal
+    pub renewed_by: Pubkey,
+    /// PDA bump seed
     pub bump: u8,
 }
 
-impl InstituteRegistry {
-    /// Check if an institute is registered
-    pub fn is_institute_registered(&self, institute: &Pubkey) -> bool {
-        self.registered_institutes.contains(institute)
-    }
+impl CertificateRenewal {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // certificate_hash
+        2 + // sequence
+        8 + // previous_expires_at
+        8 + // new_expires_at
+        8 + // renewed_at
+        32 + // renewed_by
+        1; // bump
 }
 
-// ============================================================================
-// Context Structures
-// ============================================================================
-
-#[derive(Accounts)]
-#[instruction(certificate_hash: [u8; 32])]
-pub struct AddCertificate<'info> {
-    #[account(
-        init,
-        payer = issuer,
-        space = Certificate::LEN,
-        seeds = [b"certificate", certificate_hash.as_ref()],
-        bump
-    )]
-    pub certificate: Account<'info, Certificate>,
-
-    /// Institute issuing the certificate (must be signer)
-    #[account(mut)]
-    pub issuer: Signer<'info>,
+#[account]
+pub struct IssuerStats {
+    /// Institute the counters belong to
+    pub issuer: Pubkey,
+    /// Certificates issued with `add_certificate`
+    pub issued_count: u64,
+    /// Certificates replaced with `correct_certificate`
+    pub corrected_count: u64,
+    /// Certificates revoked with `revoke_certificate`
+    pub revoked_count: u64,
+    /// Serial of the next certificate; serials below it are all indexed
+    pub next_serial: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
 
-    /// InstituteValidator program
-    /// CHECK: Program ID validation happens in instruction logic
-    pub institute_validator_program: AccountInfo<'info>,
+impl IssuerStats {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // issuer
+        8 + // issued_count
+        8 + // corrected_count
+        8 + // revoked_count
+        8 + // next_serial
+        1; // bump
+}
 
-    /// InstituteRegistry PDA from InstituteValidator program
-    /// CHECK: PDA validation happens in instruction logic
-    pub institute_registry: AccountInfo<'info>,
+/// Entry of the per-issuer certificate index, at [b"cert_by_issuer", issuer, serial]
+#[account]
+pub struct CertificateIndex {
+    /// Institute that issued the certificate
+    pub issuer: Pubkey,
+    /// Issuer-local serial number of the certificate
+    pub serial: u64,
+    /// Hash of the certificate, locating its Certificate PDA
+    pub certificate_hash: [u8; 32],
+    /// PDA bump seed
+    pub bump: u8,
+}
 
-    pub system_program: Program<'info, System>,
+impl CertificateIndex {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // issuer
+        8 + // serial
+        32 + // certificate_hash
+        1; // bump
 }
 
-#[derive(Accounts)]
+#[account]
+pub struct CredentialSchema {
+    /// Institute that registered the schema
+    pub institute: Pubkey,
+    /// Human-readable schema name, unique per institute and version
+    pub name: String,
+    /// Schema version
+    pub version: u16,
+    /// Hash of the ordered list of fields covered by the certificate hash
+    pub field_list_hash: [u8; 32],
+    /// Rule used to compute the certificate hash from the document fields
+    pub hashing_rule: HashingRule,
+    /// Signing key that registered the schema
+    pub registered_by: Pubkey,
+    /// Timestamp when the schema was registered
+    pub registered_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CredentialSchema {
+    /// The name is a PDA seed, so it is bounded by the maximum seed length
+    pub const MAX_NAME_LEN: usize = 32;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // institute
+        4 + Self::MAX_NAME_LEN + // name
+        2 + // version
+        32 + // field_list_hash
+        1 + // hashing_rule
+        32 + // registered_by
+        8 + // registered_at
+        1; // bump
+}
+
+#[account]
+pub struct HolderClaim {
+    /// Hash of the claimed certificate
+    pub certificate_hash: [u8; 32],
+    /// Wallet that proved holdership
+    pub holder: Pubkey,
+    /// Challenge supplied by the verifier
+    pub challenge: [u8; 32],
+    /// Timestamp when the claim was made
+    pub claimed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl HolderClaim {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // certificate_hash
+        32 + // holder
+        32 + // challenge
+        8 + // claimed_at
+        1; // bump
+}
+
+#[account]
+pub struct CertificateBatch {
+    /// Merkle root over the certificate hashes of the batch (see `merkle_leaf`)
+    pub merkle_root: [u8; 32],
+    /// Public key of the issuing institute
+    pub issuer: Pubkey,
+    /// Signing key that issued the batch (institute key or delegate)
+    pub issued_by: Pubkey,
+    /// Number of certificates anchored by the root
+    pub leaf_count: u32,
+    /// Timestamp when the batch was issued
+    pub issued_at: i64,
+    /// Timestamp after which every certificate in the batch is expired (if any)
+    pub expires_at: Option<i64>,
+    /// Number of revoked leaves
+    pub revoked_count: u32,
+    /// One bit per leaf, set when the leaf is revoked
+    pub revocation_bitmap: Vec<u8>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CertificateBatch {
+    /// Upper bound keeps the bitmap within the 10 KiB limit for accounts created via CPI
+    pub const MAX_LEAVES: u32 = 65_536;
+
+    pub const BASE_LEN: usize = 8 + // discriminator
+        32 + // merkle_root
+        32 + // issuer
+        32 + // issued_by
+        4 + // leaf_count
+        8 + // issued_at
+        1 + 8 + // expires_at (Option)
+        4 + // revoked_count
+        4 + // revocation_bitmap Vec prefix
+        1; // bump
+
+    /// Calculate space needed for a batch of n leaves
+    pub fn space(leaf_count: u32) -> usize {
+        Self::BASE_LEN + Self::bitmap_len(leaf_count)
+    }
+
+    /// Number of bitmap bytes needed for n leaves
+    pub fn bitmap_len(leaf_count: u32) -> usize {
+        (leaf_count as usize).div_ceil(8)
+    }
+
+    /// Check a leaf hash and proof against the stored root
+    pub fn contains(&self, leaf_hash: &[u8; 32], leaf_index: u32, proof: &[[u8; 32]]) -> bool {
+        leaf_index < self.leaf_count
+            && proof.len() == merkle_depth(self.leaf_count)
+            && merkle_root_from_proof(leaf_hash, leaf_index, proof) == self.merkle_root
+    }
+
+    /// Check if a leaf has been revoked
+    pub fn is_revoked(&self, leaf_index: u32) -> bool {
+        self.revocation_bitmap[leaf_index as usize / 8] & (1 << (leaf_index % 8)) != 0
+    }
+
+    fn set_revoked(&mut self, leaf_index: u32) {
+        self.revocation_bitmap[leaf_index as usize / 8] |= 1 << (leaf_index % 8);
+    }
+
+    /// Derive the effective state of a leaf at the given time
+    pub fn leaf_state(&self, leaf_index: u32, now: i64) -> CertificateState {
+        if self.is_revoked(leaf_index) {
+            CertificateState::Revoked
+        } else if self.expires_at.is_some_and(|expires_at| now >= expires_at) {
+            CertificateState::Expired
+        } else {
+            CertificateState::Valid
+        }
+    }
+}
+
+#[account]
+pub struct BatchLeafRevocation {
+    /// Batch the revoked leaf belongs to
+    pub batch: Pubkey,
+    /// Certificate hash of the revoked leaf
+    pub leaf_hash: [u8; 32],
+    /// Position of the leaf in the batch
+    pub leaf_index: u32,
+    /// Reason given by the issuer for the revocation
+    pub reason: RevocationReason,
+    /// Hash of an off-chain free-text revocation note
+    pub note_hash: Option<[u8; 32]>,
+    /// Timestamp when the leaf was revoked
+    pub revoked_at: i64,
+    /// Signing key that revoked the leaf
+    pub revoked_by: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BatchLeafRevocation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // batch
+        32 + // leaf_hash
+        4 + // leaf_index
+        1 + // reason (enum)
+        1 + 32 + // note_hash (Option)
+        8 + // revoked_at
+        32 + // revoked_by
+        1; // bump
+}
+
+/// Parameters of a CertificateFilter generation
+/// Versions are only ever appended; a filter keeps the version it was created with
+pub struct FilterParams {
+    /// Parameter version recorded in the filter
+    pub version: u32,
+    /// Number of bits in use (at most CertificateFilter::CAPACITY_BITS)
+    pub num_bits: u64,
+    /// Number of bits set per certificate hash
+    pub num_hashes: u32,
+    /// Fill rate, in basis points, from which the filter may be rotated
+    pub max_fill_bps: u32,
+}
+
+impl FilterParams {
+    /// Every parameter version, oldest first
+    /// Version 1: ~0.8% false positives at the rotation fill rate (~830k certificates)
+    pub const VERSIONS: &'static [FilterParams] = &[FilterParams {
+        version: 1,
+        num_bits: CertificateFilter::CAPACITY_BITS,
+        num_hashes: 7,
+        max_fill_bps: 5_000,
+    }];
+
+    /// Parameters used for new filter generations
+    pub fn latest() -> &'static FilterParams {
+        &Self::VERSIONS[Self::VERSIONS.len() - 1]
+    }
+}
+
+/// Bloom filter over the hashes of individually issued certificates, for fast negative
+/// checks by clients that download the account
+/// Certificates anchored in a CertificateBatch are not inserted; their leaves are only
+/// known through Merkle proofs
+#[account(zero_copy)]
+pub struct CertificateFilter {
+    /// Generation of the filter, starting at 0
+    pub generation: u32,
+    /// FilterParams version the filter was created with
+    pub params_version: u32,
+    /// Number of bits in use
+    pub num_bits: u64,
+    /// Number of bits set per certificate hash
+    pub num_hashes: u32,
+    /// Fill rate, in basis points, from which the filter may be rotated
+    pub max_fill_bps: u32,
+    /// Number of certificate hashes inserted
+    pub inserted_count: u64,
+    /// Number of bits set
+    pub bits_set: u64,
+    /// Timestamp when the generation was created
+    pub created_at: i64,
+    /// Previous generation (default pubkey for generation 0)
+    pub previous: Pubkey,
+    /// Next generation, set on rotation (default pubkey while the filter is current)
+    pub successor: Pubkey,
+    /// Filter bits (1 MiB, the size of the filter clients used to rebuild themselves)
+    pub bits: [u8; 1_048_576],
+}
+
+impl CertificateFilter {
+    pub const CAPACITY_BITS: u64 = 1_048_576 * 8;
+
+    pub const LEN: usize = 8 + // discriminator
+        4 + // generation
+        4 + // params_version
+        8 + // num_bits
+        4 + // num_hashes
+        4 + // max_fill_bps
+        8 + // inserted_count
+        8 + // bits_set
+        8 + // created_at
+        32 + // previous
+        32 + // successor
+        1_048_576; // bits
+
+    fn init(
+        &mut self,
+        generation: u32,
+        params: &FilterParams,
+        previous: Option<Pubkey>,
+        now: i64,
+    ) {
+        self.generation = generation;
+        self.params_version = params.version;
+        self.num_bits = params.num_bits;
+        self.num_hashes = params.num_hashes;
+        self.max_fill_bps = params.max_fill_bps;
+        self.created_at = now;
+        self.previous = previous.unwrap_or_default();
+    }
+
+    /// Bit positions of a certificate hash, by double hashing over two words of the hash
+    /// (certificate hashes are SHA-256 digests, so their words are already uniform)
+    fn positions(&self, certificate_hash: &[u8; 32]) -> impl Iterator<Item = usize> {
+        let word = |i: usize| {
+            u64::from_le_bytes(certificate_hash[i * 8..(i + 1) * 8].try_into().unwrap())
+        };
+        let (h1, h2) = (word(0), word(1) | 1);
+        let num_bits = self.num_bits;
+        (0..self.num_hashes as u64)
+            .map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % num_bits) as usize)
+    }
+
+    fn bit(&self, position: usize) -> bool {
+        self.bits[position / 8] & (1 << (position % 8)) != 0
+    }
+
+    /// Insert a certificate hash
+    pub fn insert(&mut self, certificate_hash: &[u8; 32]) {
+        for position in self.positions(certificate_hash) {
+            if !self.bit(position) {
+                self.bits[position / 8] |= 1 << (position % 8);
+                self.bits_set += 1;
+            }
+        }
+        self.inserted_count += 1;
+    }
+
+    /// Check whether a certificate hash may have been inserted
+    /// False means the hash was definitely not inserted into this generation
+    pub fn contains(&self, certificate_hash: &[u8; 32]) -> bool {
+        self.positions(certificate_hash).all(|position| self.bit(position))
+    }
+
+    /// Share of bits set, in basis points
+    pub fn fill_bps(&self) -> u64 {
+        self.bits_set * 10_000 / self.num_bits
+    }
+
+    /// Whether the filter has reached the fill rate from which it may be rotated
+    pub fn is_full(&self) -> bool {
+        self.fill_bps() >= self.max_fill_bps as u64
+    }
+}
+
+/// Singleton pointing at the current CertificateFilter generation
+#[account]
+pub struct CertificateFilterState {
+    /// Filter that certificates are currently inserted into
+    pub current: Pubkey,
+    /// Generation of the current filter
+    pub generation: u32,
+    /// Timestamp when the current filter was created
+    pub rotated_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CertificateFilterState {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // current
+        4 + // generation
+        8 + // rotated_at
+        1; // bump
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Deserialize an InstituteValidator account whose PDA seeds were verified by the
+/// account constraints, returning None if it was never created
+fn load_validator_account<T: AccountDeserialize + Owner>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.data_is_empty() {
+        return Ok(None);
+    }
+    require!(
+        info.owner == &T::owner(),
+        CertificateError::InvalidInstituteRegistry
+    );
+    Ok(Some(T::try_deserialize(&mut &info.data.borrow()[..])?))
+}
+
+/// Resolve the InstituteKey PDA of the signing key to its institute identity,
+/// checking that the key is still active and grants `permission`
+fn resolve_issuer_key(issuer_key: &AccountInfo, permission: u8, now: i64) -> Result<Pubkey> {
+    // A key record that was never created means the key belongs to no institute
+    let key = load_validator_account::<InstituteKey>(issuer_key)?
+        .ok_or(CertificateError::IssuerNotRegistered)?;
+    require!(key.is_active(now), CertificateError::IssuerKeyRetired);
+    require!(
+        key.has_permission(permission),
+        CertificateError::MissingIssuerPermission
+    );
+
+    Ok(key.institute)
+}
+
+/// Resolve the signing key to its institute identity and verify that
+/// `issuer_entry` is the InstituteEntry of that institute and that it is
+/// currently registered
+fn require_registered_issuer(
+    issuer_key: &AccountInfo,
+    issuer_entry: &AccountInfo,
+    permission: u8,
+    now: i64,
+) -> Result<Pubkey> {
+    let issuer = resolve_issuer_key(issuer_key, permission, now)?;
+
+    // An entry that was never created means the issuer was never admitted
+    let entry = load_validator_account::<InstituteEntry>(issuer_entry)?
+        .ok_or(CertificateError::IssuerNotRegistered)?;
+
+    // InstituteValidator only writes an entry at the PDA of its own institute
+    require!(
+        entry.institute == issuer,
+        CertificateError::InvalidInstituteRegistry
+    );
+    require!(entry.is_active(), CertificateError::IssuerNotRegistered);
+
+    Ok(issuer)
+}
+
+/// Insert a certificate hash into the current CertificateFilter generation
+/// Issuance does not depend on the filter until it is initialized; from then on the
+/// filter passed must be the one recorded in the CertificateFilterState
+fn record_in_filter(
+    state_info: &AccountInfo,
+    filter: Option<&AccountLoader<CertificateFilter>>,
+    certificate_hash: &[u8; 32],
+) -> Result<()> {
+    if state_info.data_is_empty() {
+        return Ok(());
+    }
+    let state = CertificateFilterState::try_deserialize(&mut &state_info.data.borrow()[..])?;
+    let filter = filter.ok_or(CertificateError::MissingCertificateFilter)?;
+    require_keys_eq!(filter.key(), state.current, CertificateError::FilterRotated);
+
+    filter.load_mut()?.insert(certificate_hash);
+    Ok(())
+}
+
+/// Load the issuer's IssuerStats, creating it on first use (institutes that issued before
+/// IssuerStats existed get theirs with their next issuance, correction or revocation)
+fn load_issuer_stats<'info>(
+    issuer: &Pubkey,
+    stats_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<IssuerStats> {
+    let (stats_pda, stats_bump) =
+        Pubkey::find_program_address(&[b"issuer_stats", issuer.as_ref()], &crate::ID);
+    require!(
+        stats_info.key() == stats_pda && stats_info.is_writable,
+        CertificateError::InvalidIssuerIndex
+    );
+
+    if !stats_info.data_is_empty() {
+        require!(
+            stats_info.owner == &crate::ID,
+            CertificateError::InvalidIssuerIndex
+        );
+        return IssuerStats::try_deserialize(&mut &stats_info.data.borrow()[..]);
+    }
+
+    create_pda_account(
+        stats_info,
+        IssuerStats::LEN,
+        &crate::ID,
+        &[b"issuer_stats", issuer.as_ref(), &[stats_bump]],
+        payer,
+        system_program,
+    )?;
+    Ok(IssuerStats {
+        issuer: *issuer,
+        issued_count: 0,
+        corrected_count: 0,
+        revoked_count: 0,
+        next_serial: 0,
+        bump: stats_bump,
+    })
+}
+
+/// Give a new certificate the issuer's next serial: create the issuer's IssuerStats on
+/// first use, create the CertificateIndex PDA of the serial, and advance the serial and
+/// the counter selected by `counter`
+fn index_certificate<'info>(
+    issuer: &Pubkey,
+    certificate_hash: [u8; 32],
+    counter: fn(&mut IssuerStats) -> &mut u64,
+    stats_info: &AccountInfo<'info>,
+    index_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u64> {
+    let mut stats = load_issuer_stats(issuer, stats_info, payer, system_program)?;
+
+    let serial = stats.next_serial;
+    let (index_pda, index_bump) = Pubkey::find_program_address(
+        &[b"cert_by_issuer", issuer.as_ref(), &serial.to_le_bytes()],
+        &crate::ID,
+    );
+    require!(
+        index_info.key() == index_pda && index_info.is_writable && index_info.data_is_empty(),
+        CertificateError::InvalidIssuerIndex
+    );
+    create_pda_account(
+        index_info,
+        CertificateIndex::LEN,
+        &crate::ID,
+        &[b"cert_by_issuer", issuer.as_ref(), &serial.to_le_bytes(), &[index_bump]],
+        payer,
+        system_program,
+    )?;
+    let index = CertificateIndex {
+        issuer: *issuer,
+        serial,
+        certificate_hash,
+        bump: index_bump,
+    };
+    index.try_serialize(&mut &mut index_info.data.borrow_mut()[..])?;
+
+    stats.next_serial += 1;
+    *counter(&mut stats) += 1;
+    stats.try_serialize(&mut &mut stats_info.data.borrow_mut()[..])?;
+
+    Ok(serial)
+}
+
+/// Deserialize a Certificate passed as a remaining account
+fn load_certificate(info: &AccountInfo) -> Result<Certificate> {
+    require!(info.owner == &crate::ID, CertificateError::InvalidLineage);
+    Certificate::try_deserialize(&mut &info.data.borrow()[..])
+}
+
+/// Salted commitment to a holder wallet, as stored in `CertificateHolder::Commitment`
+pub fn holder_commitment(holder: &Pubkey, salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[b"holder", holder.as_ref(), salt.as_ref()]).to_bytes()
+}
+
+/// Registry standing of an issuer at verification time
+struct IssuerStanding {
+    status: InstituteStatus,
+    removed_at: Option<i64>,
+    policy: RemovedIssuerPolicy,
+}
+
+impl IssuerStanding {
+    /// Combine the issuer's InstituteEntry with the governance RemovedIssuerPolicy
+    fn new(entry: &InstituteEntry, config: &GovernanceConfig) -> Self {
+        Self {
+            status: entry.status.clone(),
+            removed_at: entry.removed_at,
+            policy: config.removed_issuer_policy.clone(),
+        }
+    }
+
+    /// Flag a certificate issued at `issued_at` according to the RemovedIssuerPolicy
+    /// Revocations and corrections take precedence over the issuer's standing
+    fn apply(&self, state: CertificateState, issued_at: i64) -> CertificateState {
+        let flagged = match (&self.status, &self.policy) {
+            (InstituteStatus::Active, _) => false,
+            (InstituteStatus::Removed, RemovedIssuerPolicy::FlagAll) => true,
+            (InstituteStatus::Removed, RemovedIssuerPolicy::Grandfather) => self
+                .removed_at
+                .is_none_or(|removed_at| issued_at >= removed_at),
+        };
+
+        match state {
+            CertificateState::Valid | CertificateState::Expired if flagged => {
+                CertificateState::IssuerRemoved
+            }
+            state => state,
+        }
+    }
+}
+
+// ============================================================================
+// Merkle Proofs
+// ============================================================================
+//
+// Batches commit to a binary SHA-256 Merkle tree. Leaves are domain-separated
+// from interior nodes, and the leaf level is padded with zeroed nodes up to the
+// next power of two. A proof lists sibling nodes from the leaf level upwards;
+// the bits of the leaf index select whether each sibling sits left or right.
+
+/// Hash a certificate hash into a Merkle leaf node
+pub fn merkle_leaf(certificate_hash: &[u8; 32]) -> [u8; 32] {
+    hashv(&[&[0x00], certificate_hash.as_ref()]).to_bytes()
+}
+
+/// Hash two child nodes into their parent node
+pub fn merkle_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[&[0x01], left.as_ref(), right.as_ref()]).to_bytes()
+}
+
+/// Depth of the tree for a given number of leaves (also the proof length)
+pub fn merkle_depth(leaf_count: u32) -> usize {
+    leaf_count.next_power_of_two().trailing_zeros() as usize
+}
+
+/// Recompute the root from a certificate hash and its proof
+pub fn merkle_root_from_proof(
+    certificate_hash: &[u8; 32],
+    leaf_index: u32,
+    proof: &[[u8; 32]],
+) -> [u8; 32] {
+    let mut node = merkle_leaf(certificate_hash);
+    let mut index = leaf_index;
+    for sibling in proof {
+        node = if index & 1 == 0 {
+            merkle_node(&node, sibling)
+        } else {
+            merkle_node(sibling, &node)
+        };
+        index >>= 1;
+    }
+    node
+}
+
+// ============================================================================
+// Enums
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum RevocationReason {
+    /// Certificate was obtained or issued fraudulently
+    Fraud,
+    /// Certificate was issued by mistake
+    ClericalError,
+    /// Underlying degree or qualification was withdrawn
+    DegreeWithdrawn,
+    /// Any other reason (see note hash)
+    Other,
+}
+
+/// Canonical rule for computing a certificate hash from its document
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum HashingRule {
+    /// SHA-256 over the raw document bytes
+    Sha256Document,
+    /// SHA-256 over the canonical field serialization, version 1 (see `cert-val-hashing`)
+    CanonicalV1,
+}
+
+/// Holder a certificate is bound to at issuance
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum CertificateHolder {
+    /// Holder wallet, stored in the clear
+    Wallet(Pubkey),
+    /// Salted commitment to the holder wallet (see `holder_commitment`)
+    Commitment([u8; 32]),
+}
+
+/// Effective state of a certificate as reported by `verify_certificate`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum CertificateState {
+    Valid,
+    Expired,
+    Corrected,
+    Revoked,
+    /// Issuer was removed from the registry and the RemovedIssuerPolicy flags the certificate
+    IssuerRemoved,
+}
+
+// ============================================================================
+// Context Structures
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(certificate_hash: [u8; 32])]
+pub struct AddCertificate<'info> {
+    #[account(
+        init,
+        payer = issuer,
+        space = Certificate::LEN,
+        seeds = [b"certificate", certificate_hash.as_ref()],
+        bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    /// Signing key or delegate of the institute issuing the certificate (must be signer)
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, checked for an emergency pause
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key(),
+        constraint = !institute_registry.is_paused(InstituteRegistry::PAUSE_ISSUANCE)
+            @ CertificateError::ProgramPaused
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// InstituteKey PDA of the signing key from InstituteValidator program (may not exist)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(
+        seeds = [b"institute_key", issuer.key().as_ref()],
+        bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub issuer_key: UncheckedAccount<'info>,
+
+    /// InstituteEntry PDA of the issuer from InstituteValidator program (may not exist)
+    /// CHECK: Owner and institute are verified in instruction logic
+    pub issuer_entry: UncheckedAccount<'info>,
+
+    /// IssuerStats PDA of the issuing institute (created with its first certificate)
+    /// CHECK: Seeds are verified in instruction logic, once the institute is resolved
+    #[account(mut)]
+    pub issuer_stats: UncheckedAccount<'info>,
+
+    /// CertificateIndex PDA of the institute's next serial
+    /// CHECK: Seeds are verified in instruction logic; the account is created there
+    #[account(mut)]
+    pub certificate_index: UncheckedAccount<'info>,
+
+    /// CertificateFilterState PDA (may not exist if the filter was never initialized)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(seeds = [b"certificate_filter_state"], bump)]
+    pub filter_state: UncheckedAccount<'info>,
+
+    /// Current CertificateFilter generation, updated with the new certificate hash
+    /// Required once the filter is initialized; checked against `filter_state.current`
+    #[account(mut)]
+    pub certificate_filter: Option<AccountLoader<'info, CertificateFilter>>,
+
+    /// CredentialSchema the certificate hash was computed under, registered by the issuer
+    pub credential_schema: Option<Account<'info, CredentialSchema>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(institute: Pubkey, params: CredentialSchemaParams)]
+pub struct RegisterCredentialSchema<'info> {
+    #[account(
+        init,
+        payer = issuer,
+        space = CredentialSchema::LEN,
+        seeds = [
+            b"credential_schema",
+            institute.as_ref(),
+            params.name.as_bytes(),
+            params.version.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub credential_schema: Account<'info, CredentialSchema>,
+
+    /// Signing key or delegate of the institute registering the schema (must be signer)
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, checked for an emergency pause
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key(),
+        constraint = !institute_registry.is_paused(InstituteRegistry::PAUSE_ISSUANCE)
+            @ CertificateError::ProgramPaused
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// InstituteKey PDA of the signing key from InstituteValidator program (may not exist)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(
+        seeds = [b"institute_key", issuer.key().as_ref()],
+        bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub issuer_key: UncheckedAccount<'info>,
+
+    /// InstituteEntry PDA of the issuer from InstituteValidator program (may not exist)
+    /// CHECK: Owner and institute are verified in instruction logic
+    pub issuer_entry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
 #[instruction(old_hash: [u8; 32], new_hash: [u8; 32])]
 pub struct CorrectCertificate<'info> {
     #[account(
         mut,
-        seeds = [b"certificate", old_hash.as_ref()],
-        bump = old_certificate_pda.bump,
-        constraint = old_certificate_pda.is_valid == true @ CertificateError::CertificateAlreadyInvalid
+        seeds = [b"certificate", old_hash.as_ref()],
+        bump = old_certificate_pda.bump,
+        constraint = old_certificate_pda.is_valid @ CertificateError::CertificateAlreadyInvalid
+    )]
+    pub old_certificate_pda: Account<'info, Certificate>,
+
+    #[account(
+        init,
+        payer = issuer,
+        space = Certificate::LEN,
+        seeds = [b"certificate", new_hash.as_ref()],
+        bump
+    )]
+    pub new_certificate: Account<'info, Certificate>,
+
+    /// Signing key or delegate of the original issuer correcting the certificate (must be signer)
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, checked for an emergency pause
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key(),
+        constraint = !institute_registry.is_paused(InstituteRegistry::PAUSE_ISSUANCE)
+            @ CertificateError::ProgramPaused
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// InstituteKey PDA of the signing key from InstituteValidator program (may not exist)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(
+        seeds = [b"institute_key", issuer.key().as_ref()],
+        bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub issuer_key: UncheckedAccount<'info>,
+
+    /// InstituteEntry PDA of the issuer from InstituteValidator program (may not exist)
+    /// CHECK: Owner and institute are verified in instruction logic
+    pub issuer_entry: UncheckedAccount<'info>,
+
+    /// IssuerStats PDA of the issuing institute (created with its first certificate)
+    /// CHECK: Seeds are verified in instruction logic, once the institute is resolved
+    #[account(mut)]
+    pub issuer_stats: UncheckedAccount<'info>,
+
+    /// CertificateIndex PDA of the institute's next serial
+    /// CHECK: Seeds are verified in instruction logic; the account is created there
+    #[account(mut)]
+    pub certificate_index: UncheckedAccount<'info>,
+
+    /// CertificateFilterState PDA (may not exist if the filter was never initialized)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(seeds = [b"certificate_filter_state"], bump)]
+    pub filter_state: UncheckedAccount<'info>,
+
+    /// Current CertificateFilter generation, updated with the new certificate hash
+    /// Required once the filter is initialized; checked against `filter_state.current`
+    #[account(mut)]
+    pub certificate_filter: Option<AccountLoader<'info, CertificateFilter>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(certificate_hash: [u8; 32])]
+pub struct ExtendCertificateExpiry<'info> {
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate_hash.as_ref()],
+        bump = certificate.bump,
+        constraint = certificate.is_valid @ CertificateError::CertificateAlreadyInvalid
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        init,
+        payer = issuer,
+        space = CertificateRenewal::LEN,
+        seeds = [
+            b"certificate_renewal",
+            certificate_hash.as_ref(),
+            certificate.renewal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub renewal: Account<'info, CertificateRenewal>,
+
+    /// Signing key or delegate of the original issuer renewing the certificate (must be signer)
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, checked for an emergency pause
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key(),
+        constraint = !institute_registry.is_paused(InstituteRegistry::PAUSE_ISSUANCE)
+            @ CertificateError::ProgramPaused
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// InstituteKey PDA of the signing key from InstituteValidator program (may not exist)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(
+        seeds = [b"institute_key", issuer.key().as_ref()],
+        bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub issuer_key: UncheckedAccount<'info>,
+
+    /// InstituteEntry PDA of the issuer from InstituteValidator program (may not exist)
+    /// CHECK: Owner and institute are verified in instruction logic
+    pub issuer_entry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(certificate_hash: [u8; 32])]
+pub struct RevokeCertificate<'info> {
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate_hash.as_ref()],
+        bump = certificate.bump,
+        constraint = certificate.is_valid @ CertificateError::CertificateAlreadyInvalid
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    /// Signing key or delegate of the original issuer revoking the certificate (must be signer)
+    /// Pays for the institute's IssuerStats if it does not exist yet
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, checked for an emergency pause
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key(),
+        constraint = !institute_registry.is_paused(InstituteRegistry::PAUSE_REVOCATION)
+            @ CertificateError::ProgramPaused
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// InstituteKey PDA of the signing key from InstituteValidator program (may not exist)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(
+        seeds = [b"institute_key", issuer.key().as_ref()],
+        bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub issuer_key: UncheckedAccount<'info>,
+
+    /// IssuerStats PDA of the institute that issued the certificate (created if missing)
+    /// CHECK: Seeds are verified in instruction logic, once the institute is resolved
+    #[account(mut)]
+    pub issuer_stats: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddCertificateBatch<'info> {
+    /// CertificateBatch PDA at `[b"certificate_batch", issuer institute, merkle_root]`
+    /// CHECK: Seeds are verified in instruction logic, once the institute is resolved;
+    /// the account is created there
+    #[account(mut)]
+    pub batch: UncheckedAccount<'info>,
+
+    /// Signing key or delegate of the institute issuing the batch (must be signer)
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, checked for an emergency pause
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key(),
+        constraint = !institute_registry.is_paused(InstituteRegistry::PAUSE_ISSUANCE)
+            @ CertificateError::ProgramPaused
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// InstituteKey PDA of the signing key from InstituteValidator program (may not exist)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(
+        seeds = [b"institute_key", issuer.key().as_ref()],
+        bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub issuer_key: UncheckedAccount<'info>,
+
+    /// InstituteEntry PDA of the issuer from InstituteValidator program (may not exist)
+    /// CHECK: Owner and institute are verified in instruction logic
+    pub issuer_entry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(leaf_hash: [u8; 32], leaf_index: u32)]
+pub struct RevokeBatchLeaf<'info> {
+    #[account(
+        mut,
+        seeds = [b"certificate_batch", batch.issuer.as_ref(), batch.merkle_root.as_ref()],
+        bump = batch.bump
     )]
-    pub old_certificate_pda: Account<'info, Certificate>,
+    pub batch: Account<'info, CertificateBatch>,
 
     #[account(
         init,
         payer = issuer,
-        space = Certificate::LEN,
-        seeds = [b"certificate", new_hash.as_ref()],
+        space = BatchLeafRevocation::LEN,
+        seeds = [b"batch_revocation", batch.key().as_ref(), leaf_index.to_le_bytes().as_ref()],
         bump
     )]
-    pub new_certificate: Account<'info, Certificate>,
+    pub revocation: Account<'info, BatchLeafRevocation>,
 
-    /// Institute correcting the certificate (must be signer and original issuer)
+    /// Signing key or delegate of the batch issuer revoking the leaf (must be signer)
     #[account(mut)]
     pub issuer: Signer<'info>,
 
-    /// InstituteValidator program
-    /// CHECK: Program ID validation happens in instruction logic
-    pub institute_validator_program: AccountInfo<'info>,
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, checked for an emergency pause
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key(),
+        constraint = !institute_registry.is_paused(InstituteRegistry::PAUSE_REVOCATION)
+            @ CertificateError::ProgramPaused
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// InstituteKey PDA of the signing key from InstituteValidator program (may not exist)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(
+        seeds = [b"institute_key", issuer.key().as_ref()],
+        bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub issuer_key: UncheckedAccount<'info>,
+
+    /// IssuerStats PDA of the institute that issued the batch (created if missing)
+    /// CHECK: Seeds are verified in instruction logic, once the institute is resolved
+    #[account(mut)]
+    pub issuer_stats: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(leaf_hash: [u8; 32], leaf_index: u32)]
+pub struct VerifyInBatch<'info> {
+    /// Batch anchoring the certificate
+    #[account(
+        seeds = [b"certificate_batch", batch.issuer.as_ref(), batch.merkle_root.as_ref()],
+        bump = batch.bump
+    )]
+    pub batch: Account<'info, CertificateBatch>,
+
+    /// Revocation record for the leaf (only exists if the leaf was revoked)
+    #[account(
+        seeds = [b"batch_revocation", batch.key().as_ref(), leaf_index.to_le_bytes().as_ref()],
+        bump = revocation.bump
+    )]
+    pub revocation: Option<Account<'info, BatchLeafRevocation>>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, checked for an emergency pause
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key(),
+        constraint = !institute_registry.is_paused(InstituteRegistry::PAUSE_VERIFICATION)
+            @ CertificateError::ProgramPaused
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// InstituteEntry PDA of the issuer from InstituteValidator program
+    #[account(
+        seeds = [b"institute", batch.issuer.as_ref()],
+        bump = issuer_entry.bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub issuer_entry: Account<'info, InstituteEntry>,
+
+    /// GovernanceConfig PDA from InstituteValidator program
+    #[account(
+        seeds = [b"governance_config"],
+        bump = governance_config.bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// InstituteProfile PDA of the issuer from InstituteValidator program
+    #[account(
+        seeds = [b"institute_profile", batch.issuer.as_ref()],
+        bump = issuer_profile.bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub issuer_profile: Option<Account<'info, InstituteProfile>>,
+}
+
+#[derive(Accounts)]
+#[instruction(certificate_hash: [u8; 32], challenge: [u8; 32])]
+pub struct ProveHoldership<'info> {
+    #[account(
+        seeds = [b"certificate", certificate_hash.as_ref()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        init,
+        payer = holder,
+        space = HolderClaim::LEN,
+        seeds = [b"holder_claim", certificate_hash.as_ref(), challenge.as_ref()],
+        bump
+    )]
+    pub holder_claim: Account<'info, HolderClaim>,
+
+    /// Holder proving control of the certificate (must be signer)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
 
-    /// InstituteRegistry PDA from InstituteValidator program
-    /// CHECK: PDA validation happens in instruction logic
-    pub institute_registry: AccountInfo<'info>,
+    /// InstituteRegistry PDA from InstituteValidator program, checked for an emergency pause
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key(),
+        constraint = !institute_registry.is_paused(InstituteRegistry::PAUSE_VERIFICATION)
+            @ CertificateError::ProgramPaused
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
 
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct RequireValidCertificate<'info> {
+    /// Certificate to check
+    pub certificate: Account<'info, Certificate>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, checked for an emergency pause
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key(),
+        constraint = !institute_registry.is_paused(InstituteRegistry::PAUSE_VERIFICATION)
+            @ CertificateError::ProgramPaused
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// InstituteEntry PDA of the issuer from InstituteValidator program
+    #[account(
+        seeds = [b"institute", certificate.issuer.as_ref()],
+        bump = issuer_entry.bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub issuer_entry: Account<'info, InstituteEntry>,
+}
+
+#[derive(Accounts)]
+pub struct GetCertificateLineage<'info> {
+    /// Certificate whose lineage is requested (any version of the chain)
+    pub certificate: Account<'info, Certificate>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, checked for an emergency pause
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key(),
+        constraint = !institute_registry.is_paused(InstituteRegistry::PAUSE_VERIFICATION)
+            @ CertificateError::ProgramPaused
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(serial: u64)]
+pub struct GetIssuerCertificate<'info> {
+    /// IssuerStats PDA of the institute
+    #[account(
+        seeds = [b"issuer_stats", issuer_stats.issuer.as_ref()],
+        bump = issuer_stats.bump
+    )]
+    pub issuer_stats: Account<'info, IssuerStats>,
+
+    /// CertificateIndex PDA of the institute's `serial`-th certificate
+    #[account(
+        seeds = [
+            b"cert_by_issuer",
+            issuer_stats.issuer.as_ref(),
+            serial.to_le_bytes().as_ref()
+        ],
+        bump = certificate_index.bump
+    )]
+    pub certificate_index: Account<'info, CertificateIndex>,
+
+    /// Certificate the index points to
+    #[account(
+        seeds = [b"certificate", certificate_index.certificate_hash.as_ref()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+}
+
 #[derive(Accounts)]
 pub struct VerifyCertificate<'info> {
     /// Certificate to verify
     pub certificate: Account<'info, Certificate>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, checked for an emergency pause
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key(),
+        constraint = !institute_registry.is_paused(InstituteRegistry::PAUSE_VERIFICATION)
+            @ CertificateError::ProgramPaused
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// InstituteEntry PDA of the issuer from InstituteValidator program
+    #[account(
+        seeds = [b"institute", certificate.issuer.as_ref()],
+        bump = issuer_entry.bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub issuer_entry: Account<'info, InstituteEntry>,
+
+    /// GovernanceConfig PDA from InstituteValidator program
+    #[account(
+        seeds = [b"governance_config"],
+        bump = governance_config.bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// InstituteProfile PDA of the issuer from InstituteValidator program
+    #[account(
+        seeds = [b"institute_profile", certificate.issuer.as_ref()],
+        bump = issuer_profile.bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub issuer_profile: Option<Account<'info, InstituteProfile>>,
+
+    /// CredentialSchema the certificate was issued under
+    #[account(
+        constraint = certificate.schema == Some(credential_schema.key())
+            @ CertificateError::InvalidCredentialSchema
+    )]
+    pub credential_schema: Option<Account<'info, CredentialSchema>>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeCertificateFilter<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = CertificateFilterState::LEN,
+        seeds = [b"certificate_filter_state"],
+        bump
+    )]
+    pub filter_state: Account<'info, CertificateFilterState>,
+
+    /// First filter generation, created by the caller and still zeroed
+    #[account(zero)]
+    pub filter: AccountLoader<'info, CertificateFilter>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, holding the authorities
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// One of the registry authorities
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RotateCertificateFilter<'info> {
+    #[account(
+        mut,
+        seeds = [b"certificate_filter_state"],
+        bump = filter_state.bump
+    )]
+    pub filter_state: Account<'info, CertificateFilterState>,
+
+    /// Filter generation being rotated out
+    #[account(mut, address = filter_state.current @ CertificateError::FilterRotated)]
+    pub current_filter: AccountLoader<'info, CertificateFilter>,
+
+    /// Next filter generation, created by the caller and still zeroed
+    #[account(zero)]
+    pub filter: AccountLoader<'info, CertificateFilter>,
+
+    pub institute_validator_program: Program<'info, InstituteValidator>,
+
+    /// InstituteRegistry PDA from InstituteValidator program, holding the authorities
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        seeds::program = institute_validator_program.key()
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// One of the registry authorities
+    pub authority: Signer<'info>,
 }
 
 // ============================================================================
@@ -279,6 +2293,9 @@ pub struct VerifyCertificate<'info> {
 pub struct CertificateAdded {
     pub certificate_hash: [u8; 32],
     pub issuer: Pubkey,
+    /// Issuer-local serial of the certificate
+    pub serial: u64,
+    pub signed_by: Pubkey,
     pub timestamp: i64,
 }
 
@@ -286,10 +2303,94 @@ pub struct CertificateAdded {
 pub struct CertificateCorrected {
     pub old_hash: [u8; 32],
     pub new_hash: [u8; 32],
+    /// Version of the new certificate in the correction chain
+    pub version: u16,
+    /// Issuer-local serial of the new certificate
+    pub serial: u64,
+    pub issuer: Pubkey,
+    pub signed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CertificateBatchAdded {
+    pub merkle_root: [u8; 32],
+    pub issuer: Pubkey,
+    pub signed_by: Pubkey,
+    pub leaf_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CertificateRenewed {
+    pub certificate_hash: [u8; 32],
+    pub issuer: Pubkey,
+    pub signed_by: Pubkey,
+    pub previous_expires_at: i64,
+    pub new_expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CertificateRevoked {
+    pub certificate_hash: [u8; 32],
     pub issuer: Pubkey,
+    pub signed_by: Pubkey,
+    pub reason: RevocationReason,
+    pub note_hash: Option<[u8; 32]>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialSchemaRegistered {
+    pub schema: Pubkey,
+    pub institute: Pubkey,
+    pub name: String,
+    pub version: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CertificateFilterCreated {
+    pub filter: Pubkey,
+    pub generation: u32,
+    pub params_version: u32,
+    /// Generation the new filter replaces (None for the first filter)
+    pub previous: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HoldershipProven {
+    pub certificate_hash: [u8; 32],
+    pub holder: Pubkey,
+    pub challenge: [u8; 32],
     pub timestamp: i64,
 }
 
+// ============================================================================
+// Instruction Parameters
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CredentialSchemaParams {
+    pub name: String,
+    pub version: u16,
+    pub field_list_hash: [u8; 32],
+    pub hashing_rule: HashingRule,
+}
+
+impl CredentialSchemaParams {
+    /// Check the name fits the space reserved in CredentialSchema and its PDA seed
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.name.is_empty() && self.name.len() <= CredentialSchema::MAX_NAME_LEN,
+            CertificateError::InvalidSchemaName
+        );
+        Ok(())
+    }
+}
+
 // ============================================================================
 // Return Types
 // ============================================================================
@@ -298,10 +2399,101 @@ pub struct CertificateCorrected {
 pub struct CertificateStatus {
     pub certificate_hash: [u8; 32],
     pub issuer: Pubkey,
+    /// Signing key (institute key or delegate) that issued the certificate
+    pub issued_by: Pubkey,
+    /// Holder bound at issuance (batch leaves carry no holder)
+    pub holder: Option<CertificateHolder>,
+    /// CredentialSchema the certificate was issued under (batch leaves carry no schema)
+    pub schema: Option<Pubkey>,
+    /// True only when `state` is `Valid`
     pub is_valid: bool,
+    pub state: CertificateState,
     pub issued_at: i64,
+    pub expires_at: Option<i64>,
+    pub renewal_count: u16,
     pub corrected_at: Option<i64>,
     pub replacement_hash: Option<[u8; 32]>,
+    /// Certificate this one corrects (None for an original issuance and for batch leaves)
+    pub previous_hash: Option<[u8; 32]>,
+    /// Position in the correction chain (1 for an original issuance and for batch leaves)
+    pub version: u16,
+    pub revoked_at: Option<i64>,
+    pub revocation_reason: Option<RevocationReason>,
+    pub revocation_note_hash: Option<[u8; 32]>,
+    pub revoked_by: Option<Pubkey>,
+    /// Registry status of the issuer at verification time
+    pub issuer_status: InstituteStatus,
+    /// Timestamp when the issuer was removed from the registry (if applicable)
+    pub issuer_removed_at: Option<i64>,
+    /// Issuer profile, if the InstituteProfile account was supplied
+    pub issuer_profile: Option<IssuerProfile>,
+    /// Schema details, if the CredentialSchema account was supplied
+    pub credential_schema: Option<CredentialSchemaInfo>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct IssuerProfile {
+    pub display_name: String,
+    pub country_code: [u8; 2],
+    pub accreditation_body: String,
+    pub accreditation_id: String,
+    pub uri: String,
+    pub logo_hash: [u8; 32],
+}
+
+impl From<&InstituteProfile> for IssuerProfile {
+    fn from(profile: &InstituteProfile) -> Self {
+        Self {
+            display_name: profile.display_name.clone(),
+            country_code: profile.country_code,
+            accreditation_body: profile.accreditation_body.clone(),
+            accreditation_id: profile.accreditation_id.clone(),
+            uri: profile.uri.clone(),
+            logo_hash: profile.logo_hash,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CredentialSchemaInfo {
+    pub institute: Pubkey,
+    pub name: String,
+    pub version: u16,
+    pub field_list_hash: [u8; 32],
+    pub hashing_rule: HashingRule,
+}
+
+impl From<&CredentialSchema> for CredentialSchemaInfo {
+    fn from(schema: &CredentialSchema) -> Self {
+        Self {
+            institute: schema.institute,
+            name: schema.name.clone(),
+            version: schema.version,
+            field_list_hash: schema.field_list_hash,
+            hashing_rule: schema.hashing_rule.clone(),
+        }
+    }
+}
+
+/// Correction history of a certificate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CertificateLineage {
+    /// Every version of the certificate, from the original issuance to the head
+    pub entries: Vec<LineageEntry>,
+    /// Hash of the current head (the latest version)
+    pub head: [u8; 32],
+    /// Index in `entries` of the queried certificate
+    pub position: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LineageEntry {
+    pub certificate_hash: [u8; 32],
+    pub version: u16,
+    pub issued_at: i64,
+    pub corrected_at: Option<i64>,
+    /// State from corrections, revocation and expiry (issuer standing not applied)
+    pub state: CertificateState,
 }
 
 // ============================================================================
@@ -324,4 +2516,79 @@ pub enum CertificateError {
 
     #[msg("Invalid InstituteRegistry account")]
     InvalidInstituteRegistry,
+
+    #[msg("Expiry must be in the future and later than the current expiry")]
+    InvalidExpiry,
+
+    #[msg("Certificate was issued without an expiry")]
+    CertificateDoesNotExpire,
+
+    #[msg("Certificate has reached the maximum number of renewals")]
+    RenewalLimitReached,
+
+    #[msg("Batch must contain between 1 and CertificateBatch::MAX_LEAVES certificates")]
+    InvalidBatchSize,
+
+    #[msg("Merkle proof does not match the batch root")]
+    InvalidMerkleProof,
+
+    #[msg("Issuer signing key has been retired")]
+    IssuerKeyRetired,
+
+    #[msg("Issuer signing key is not permitted to perform this action")]
+    MissingIssuerPermission,
+
+    #[msg("This action is currently paused")]
+    ProgramPaused,
+
+    #[msg("Certificate has been corrected or revoked")]
+    CertificateNotValid,
+
+    #[msg("Certificate has expired")]
+    CertificateExpired,
+
+    #[msg("Certificate was issued by a different institute")]
+    UnexpectedIssuer,
+
+    #[msg("Certificate is not bound to a holder")]
+    HolderNotBound,
+
+    #[msg("Signer is not the holder of this certificate")]
+    HolderMismatch,
+
+    #[msg("Salt is required to open a holder commitment")]
+    MissingHolderSalt,
+
+    #[msg("Schema name must be between 1 and 32 bytes")]
+    InvalidSchemaName,
+
+    #[msg("CredentialSchema does not match the certificate")]
+    InvalidCredentialSchema,
+
+    #[msg("Certificate was issued under a different credential schema")]
+    UnexpectedSchema,
+
+    #[msg("Correction chain exceeds Certificate::MAX_LINEAGE_DEPTH versions")]
+    LineageTooDeep,
+
+    #[msg("Certificates do not form the complete correction chain")]
+    InvalidLineage,
+
+    #[msg("IssuerStats or CertificateIndex account does not match the issuer's next serial")]
+    InvalidIssuerIndex,
+
+    #[msg("CertificateFilter is not the current generation")]
+    FilterRotated,
+
+    #[msg("CertificateFilter is below its rotation fill rate and uses the latest parameters")]
+    FilterNotFull,
+
+    #[msg("The current CertificateFilter must be passed once the filter is initialized")]
+    MissingCertificateFilter,
+
+    #[msg("CertificateBatch account does not match the issuer and Merkle root")]
+    InvalidBatchAccount,
+
+    #[msg("Not enough registry authorities signed")]
+    AuthorityThresholdNotMet,
 }
\ No newline at end of file
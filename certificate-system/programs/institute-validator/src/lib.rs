@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 
 declare_id!("JYhgtXGuQWYvmmtiKwZJgDuaP1iPLjw3MjtwukFhAJQ");
 
@@ -8,20 +9,61 @@ declare_id!("JYhgtXGuQWYvmmtiKwZJgDuaP1iPLjw3MjtwukFhAJQ");
 pub mod institute_validator {
     use super::*;
 
-    /// Initialize the InstituteRegistry (one-time setup)
+    /// Initialize the InstituteRegistry and GovernanceConfig (one-time setup)
     /// Should be called once to create the singleton registry
-    pub fn initialize_registry(
-        ctx: Context<InitializeRegistry>,
+    /// The governance parameters can later only be changed through a governance election
+    /// The InstituteEntry, InstituteProfile and InstituteKey PDAs of every initial
+    /// institute must be passed, in order and as triples, as writable remaining accounts
+    pub fn initialize_registry<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitializeRegistry<'info>>,
         initial_institutes: Vec<Pubkey>,
+        governance: GovernanceParams,
     ) -> Result<()> {
+        governance.validate()?;
+
+        require!(
+            initial_institutes.len() <= InstituteRegistry::MAX_INSTITUTES as usize,
+            ValidatorError::RegistryFull
+        );
+
+        require!(
+            ctx.remaining_accounts.len() == initial_institutes.len() * 3,
+            ValidatorError::InstituteEntryMismatch
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        for (institute, accounts) in initial_institutes
+            .iter()
+            .zip(ctx.remaining_accounts.chunks_exact(3))
+        {
+            admit_institute(
+                institute,
+                None,
+                now,
+                &accounts[0],
+                &accounts[1],
+                &accounts[2],
+                &ctx.accounts.authority,
+                &ctx.accounts.system_program,
+            )?;
+        }
+
         let registry = &mut ctx.accounts.institute_registry;
-        
-        registry.registered_institutes = initial_institutes.clone();
-        registry.authority = ctx.accounts.authority.key();
+        registry.institute_count = initial_institutes.len() as u32;
+        registry.authorities = vec![ctx.accounts.authority.key()];
+        registry.authority_threshold = 1;
+        registry.pending_transfer = None;
+        registry.guardians = Vec::new();
+        registry.paused_scopes = 0;
+        registry.open_admissions = 0;
         registry.bump = ctx.bumps.institute_registry;
 
+        let governance_config = &mut ctx.accounts.governance_config;
+        governance_config.apply(&governance, now);
+        governance_config.bump = ctx.bumps.governance_config;
+
         emit!(RegistryInitialized {
-            authority: registry.authority,
+            authority: ctx.accounts.authority.key(),
             initial_count: initial_institutes.len() as u32,
         });
 
@@ -29,48 +71,100 @@ pub mod institute_validator {
     }
 
     /// Initiates an election for a new candidate institute
-    /// Creates a VotingState PDA to track the voting process
+    /// Creates a VotingState PDA for the next round of elections on the candidate,
+    /// so a rejected or expired candidate can be nominated again
+    /// The proposer pays for growing the registry to its current layout, if needed
+    /// Votes are accepted for `voting_period` seconds, after which anyone can finalize
     pub fn new_institute_election(
         ctx: Context<NewInstituteElection>,
         candidate_institute: Pubkey,
+        voting_period: i64,
     ) -> Result<()> {
-        let voting_state = &mut ctx.accounts.voting_state;
-        let registry = &ctx.accounts.institute_registry;
+        let registry = &mut ctx.accounts.institute_registry;
+
+        require!(
+            !registry.is_paused(InstituteRegistry::PAUSE_GOVERNANCE),
+            ValidatorError::ProgramPaused
+        );
+
+        // Every member must fit in the electorate of future elections; the candidate
+        // holds a seat while the election is open, so an approval always takes effect
+        require!(!registry.is_full(), ValidatorError::RegistryFull);
+        registry.open_admissions += 1;
 
         // Ensure candidate is not already registered
         require!(
-            !registry.is_institute_registered(&candidate_institute),
+            !load_institute_entry(&ctx.accounts.candidate_entry)?.is_some_and(|e| e.is_active()),
             ValidatorError::InstituteAlreadyRegistered
         );
 
-        // Initialize voting state
-        voting_state.candidate_institute = candidate_institute;
-        voting_state.votes_for = Vec::new();
-        voting_state.votes_against = Vec::new();
-        voting_state.total_eligible_voters = registry.registered_institutes.len() as u32;
-        voting_state.status = VotingStatus::Active;
-        voting_state.created_at = Clock::get()?.unix_timestamp;
-        voting_state.concluded_at = None;
+        // Ensure the candidate key is not a signing key of another institute
+        require!(
+            load_institute_key(&ctx.accounts.candidate_key)?
+                .is_none_or(|k| k.institute == candidate_institute),
+            ValidatorError::KeyAlreadyInUse
+        );
+
+        let voting_state = &mut ctx.accounts.voting_state;
+        voting_state.proposer = ctx.accounts.proposer.key();
         voting_state.bump = ctx.bumps.voting_state;
+        ctx.accounts.election_history.bump = ctx.bumps.election_history;
+        start_election(
+            voting_state,
+            &mut ctx.accounts.election_history,
+            ElectionKind::Admission,
+            candidate_institute,
+            registry.institute_count,
+            &ctx.accounts.governance_config,
+            voting_period,
+        )
+    }
 
-        emit!(ElectionCreated {
-            candidate: candidate_institute,
-            eligible_voters: voting_state.total_eligible_voters,
-            timestamp: voting_state.created_at,
-        });
+    /// Initiates an election to replace the governance parameters
+    /// The subject of the election is the GovernanceConfig PDA itself
+    pub fn new_governance_election(
+        ctx: Context<NewGovernanceElection>,
+        params: GovernanceParams,
+        voting_period: i64,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.institute_registry.is_paused(InstituteRegistry::PAUSE_GOVERNANCE),
+            ValidatorError::ProgramPaused
+        );
 
-        Ok(())
+        params.validate()?;
+
+        let voting_state = &mut ctx.accounts.voting_state;
+        voting_state.proposer = ctx.accounts.proposer.key();
+        voting_state.bump = ctx.bumps.voting_state;
+        ctx.accounts.election_history.bump = ctx.bumps.election_history;
+        start_election(
+            voting_state,
+            &mut ctx.accounts.election_history,
+            ElectionKind::GovernanceChange(params),
+            ctx.accounts.governance_config.key(),
+            ctx.accounts.institute_registry.institute_count,
+            &ctx.accounts.governance_config,
+            voting_period,
+        )
     }
 
-    /// Cast a vote for or against a candidate institute
-    /// Automatically admits the candidate if 100% approval is reached
+    /// Cast a vote in an active election
+    /// Votes are recorded against the voter's institute identity, not its signing key,
+    /// and the voter pays for growing the VotingState to hold it
+    /// Once every eligible voter has voted, the election is decided against the
+    /// GovernanceConfig approval threshold and its outcome applied
     pub fn vote(
         ctx: Context<Vote>,
         vote_for: bool,
     ) -> Result<()> {
         let voting_state = &mut ctx.accounts.voting_state;
-        let registry = &mut ctx.accounts.institute_registry;
-        let voter = ctx.accounts.voter.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            !ctx.accounts.institute_registry.is_paused(InstituteRegistry::PAUSE_GOVERNANCE),
+            ValidatorError::ProgramPaused
+        );
 
         // Ensure voting is still active
         require!(
@@ -78,10 +172,32 @@ pub mod institute_validator {
             ValidatorError::VotingNotActive
         );
 
-        // Verify voter is a registered institute
+        // Ensure the voting period has not ended
+        require!(
+            now < voting_state.voting_ends_at,
+            ValidatorError::VotingPeriodEnded
+        );
+
+        // Verify voter is a registered institute signing with a current key
+        let voter_entry = resolve_active_institute(
+            &ctx.accounts.voter_key,
+            &ctx.accounts.voter_entry,
+            now,
+        )?
+        .ok_or(ValidatorError::VoterNotRegistered)?;
+        let voter = voter_entry.institute;
+
+        // The electorate is fixed when the election opens: institutes admitted
+        // since then were not counted in `total_eligible_voters`
+        require!(
+            voter_entry.joined_at <= voting_state.created_at,
+            ValidatorError::VoterNotInElectorate
+        );
+
+        // The institute under review cannot vote on its own removal
         require!(
-            registry.is_institute_registered(&voter),
-            ValidatorError::VoterNotRegistered
+            !(voting_state.kind == ElectionKind::Removal && voting_state.subject == voter),
+            ValidatorError::CannotVoteOnOwnRemoval
         );
 
         // Ensure voter hasn't already voted
@@ -98,72 +214,426 @@ pub mod institute_validator {
         }
 
         emit!(VoteCast {
-            candidate: voting_state.candidate_institute,
+            subject: voting_state.subject,
             voter,
             vote_for,
-            timestamp: Clock::get()?.unix_timestamp,
+            timestamp: now,
         });
 
-        // Check if voting should conclude
+        // Conclude as soon as all eligible voters have voted
         let total_votes = voting_state.votes_for.len() + voting_state.votes_against.len();
-        
-        // Decision logic: 100% approval required
         if total_votes == voting_state.total_eligible_voters as usize {
-            // All eligible voters have voted
-            if voting_state.votes_against.is_empty() {
-                // 100% approval - admit the institute
-                registry.registered_institutes.push(voting_state.candidate_institute);
-                voting_state.status = VotingStatus::Approved;
-                
-                emit!(InstituteAdmitted {
-                    candidate: voting_state.candidate_institute,
-                    total_institutes: registry.registered_institutes.len() as u32,
-                    timestamp: Clock::get()?.unix_timestamp,
-                });
-            } else {
-                // Not 100% approval - reject
-                voting_state.status = VotingStatus::Rejected;
-                
-                emit!(InstituteRejected {
-                    candidate: voting_state.candidate_institute,
-                    votes_for: voting_state.votes_for.len() as u32,
-                    votes_against: voting_state.votes_against.len() as u32,
-                    timestamp: Clock::get()?.unix_timestamp,
-                });
-            }
-            
-            voting_state.concluded_at = Some(Clock::get()?.unix_timestamp);
+            let outcome = voting_state.outcome(&ctx.accounts.governance_config);
+            conclude_election(
+                voting_state,
+                outcome,
+                &mut ctx.accounts.election_history,
+                &mut ctx.accounts.institute_registry,
+                &mut ctx.accounts.governance_config,
+                ctx.accounts.subject_entry.as_deref(),
+                ctx.accounts.subject_profile.as_deref(),
+                ctx.accounts.subject_key.as_deref(),
+                &ctx.accounts.voter,
+                &ctx.accounts.system_program,
+                now,
+            )?;
         }
 
         Ok(())
     }
 
-    /// Remove an institute from the registry (governance function)
-    /// Requires unanimous approval from all other institutes
+    /// Resolve an election whose voting period has ended without every vote cast
+    /// Permissionless: anyone can close out a stalled election
+    /// The election expires if quorum was not reached; otherwise it is decided
+    /// against the approval threshold and its outcome applied
+    pub fn finalize_election(ctx: Context<FinalizeElection>) -> Result<()> {
+        let voting_state = &mut ctx.accounts.voting_state;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            !ctx.accounts.institute_registry.is_paused(InstituteRegistry::PAUSE_GOVERNANCE),
+            ValidatorError::ProgramPaused
+        );
+
+        require!(
+            now >= voting_state.voting_ends_at,
+            ValidatorError::VotingPeriodNotEnded
+        );
+
+        let outcome = voting_state.outcome(&ctx.accounts.governance_config);
+        conclude_election(
+            voting_state,
+            outcome,
+            &mut ctx.accounts.election_history,
+            &mut ctx.accounts.institute_registry,
+            &mut ctx.accounts.governance_config,
+            ctx.accounts.subject_entry.as_deref(),
+            ctx.accounts.subject_profile.as_deref(),
+            ctx.accounts.subject_key.as_deref(),
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            now,
+        )
+    }
+
+    /// Close a concluded election and refund its rent to the proposer
+    /// Permissionless: the rent can only go back to whoever paid for the election
+    /// The result stays recorded in the subject's ElectionHistory
+    pub fn close_election(ctx: Context<CloseElection>) -> Result<()> {
+        let voting_state = &ctx.accounts.voting_state;
+
+        emit!(ElectionClosed {
+            subject: voting_state.subject,
+            round: voting_state.round,
+            proposer: voting_state.proposer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Open an election to remove an institute from the registry
+    /// Only the registry authorities can propose a removal; the remaining members
+    /// decide it by vote, and the institute under review cannot vote
+    /// Additional authority signers are passed as remaining accounts
     pub fn remove_institute(
         ctx: Context<RemoveInstitute>,
         institute_to_remove: Pubkey,
+        voting_period: i64,
+    ) -> Result<()> {
+        let registry = &ctx.accounts.institute_registry;
+
+        require!(
+            !registry.is_paused(InstituteRegistry::PAUSE_GOVERNANCE),
+            ValidatorError::ProgramPaused
+        );
+
+        // Only the authority threshold can propose a removal
+        require_authority_threshold(
+            &registry.authorities,
+            registry.authority_threshold,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+
+        // At least one other institute must remain to vote
+        require!(
+            registry.institute_count > 1,
+            ValidatorError::CannotRemoveLastInstitute
+        );
+
+        let voting_state = &mut ctx.accounts.voting_state;
+        voting_state.proposer = ctx.accounts.authority.key();
+        voting_state.bump = ctx.bumps.voting_state;
+        ctx.accounts.election_history.bump = ctx.bumps.election_history;
+        start_election(
+            voting_state,
+            &mut ctx.accounts.election_history,
+            ElectionKind::Removal,
+            institute_to_remove,
+            registry.institute_count - 1,
+            &ctx.accounts.governance_config,
+            voting_period,
+        )
+    }
+
+    /// Propose a new M-of-N registry authority set (first step of a transfer)
+    /// Requires the threshold of current authorities; additional authority
+    /// signers are passed as remaining accounts
+    /// A new proposal replaces any pending one
+    pub fn propose_authority_transfer(
+        ctx: Context<ProposeAuthorityTransfer>,
+        new_authorities: Vec<Pubkey>,
+        new_threshold: u8,
     ) -> Result<()> {
         let registry = &mut ctx.accounts.institute_registry;
-        
-        // Only authority can initiate removal (or implement similar voting)
+        let now = Clock::get()?.unix_timestamp;
+
+        require_authority_threshold(
+            &registry.authorities,
+            registry.authority_threshold,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+
+        validate_authority_set(&new_authorities, new_threshold)?;
+
+        registry.pending_transfer = Some(AuthorityTransfer {
+            authorities: new_authorities.clone(),
+            threshold: new_threshold,
+            proposed_at: now,
+        });
+
+        emit!(AuthorityTransferProposed {
+            authorities: new_authorities,
+            threshold: new_threshold,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Accept a pending authority transfer (second step)
+    /// Requires the threshold of the proposed authorities, proving they hold the keys;
+    /// additional authority signers are passed as remaining accounts
+    pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+        let registry = &mut ctx.accounts.institute_registry;
+        let now = Clock::get()?.unix_timestamp;
+
+        let transfer = registry
+            .pending_transfer
+            .take()
+            .ok_or(ValidatorError::NoPendingAuthorityTransfer)?;
+
+        require_authority_threshold(
+            &transfer.authorities,
+            transfer.threshold,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+
+        registry.authorities = transfer.authorities;
+        registry.authority_threshold = transfer.threshold;
+
+        emit!(AuthorityTransferred {
+            authorities: registry.authorities.clone(),
+            threshold: registry.authority_threshold,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Replace the guardian set, whose members can pause the programs
+    /// Requires the threshold of registry authorities; additional authority
+    /// signers are passed as remaining accounts
+    pub fn set_guardians(ctx: Context<SetGuardians>, guardians: Vec<Pubkey>) -> Result<()> {
+        let registry = &mut ctx.accounts.institute_registry;
+
+        require_authority_threshold(
+            &registry.authorities,
+            registry.authority_threshold,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+
+        require!(
+            guardians.len() <= InstituteRegistry::MAX_GUARDIANS
+                && guardians
+                    .iter()
+                    .enumerate()
+                    .all(|(i, guardian)| !guardians[..i].contains(guardian)),
+            ValidatorError::InvalidGuardianSet
+        );
+
+        registry.guardians = guardians;
+
+        emit!(GuardiansUpdated {
+            guardians: registry.guardians.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Set which actions are paused (see `InstituteRegistry::PAUSE_*`)
+    /// Any single guardian can pause additional scopes; lifting a pause requires
+    /// the threshold of registry authorities, passed as remaining accounts
+    pub fn set_paused_scopes(ctx: Context<SetPausedScopes>, paused_scopes: u8) -> Result<()> {
+        let registry = &mut ctx.accounts.institute_registry;
+        let signer = ctx.accounts.authority.key();
+
         require!(
-            ctx.accounts.authority.key() == registry.authority,
-            ValidatorError::Unauthorized
+            paused_scopes & !InstituteRegistry::PAUSE_ALL == 0,
+            ValidatorError::InvalidPauseScopes
         );
 
-        // Find and remove the institute
-        if let Some(pos) = registry.registered_institutes.iter().position(|&x| x == institute_to_remove) {
-            registry.registered_institutes.remove(pos);
-            
-            emit!(InstituteRemoved {
-                institute: institute_to_remove,
-                timestamp: Clock::get()?.unix_timestamp,
-            });
-        } else {
-            return Err(ValidatorError::InstituteNotFound.into());
+        let only_adds_scopes = paused_scopes & registry.paused_scopes == registry.paused_scopes;
+        if !(only_adds_scopes && registry.guardians.contains(&signer)) {
+            require_authority_threshold(
+                &registry.authorities,
+                registry.authority_threshold,
+                &signer,
+                ctx.remaining_accounts,
+            )?;
+        }
+
+        registry.paused_scopes = paused_scopes;
+
+        emit!(PauseUpdated {
+            paused_scopes,
+            updated_by: signer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Rotate the signing key of the calling institute
+    /// The institute identity (and every certificate issued under it) is unchanged;
+    /// the current key keeps working until `retire_current_key_at`, then is retired
+    /// The new key cannot be the identity of another institute or of an admission candidate
+    pub fn rotate_institute_key(
+        ctx: Context<RotateInstituteKey>,
+        retire_current_key_at: i64,
+    ) -> Result<()> {
+        let entry = &mut ctx.accounts.institute_entry;
+        let current_key_record = &mut ctx.accounts.current_key_record;
+        let new_key_record = &mut ctx.accounts.new_key_record;
+        let current_key = ctx.accounts.current_key.key();
+        let new_key = ctx.accounts.new_key.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        // Only the primary signing key can rotate itself
+        require!(
+            entry.signing_key == current_key,
+            ValidatorError::NotPrimarySigningKey
+        );
+
+        // Retirement must not be backdated nor deferred beyond the grace period
+        require!(
+            retire_current_key_at >= now
+                && retire_current_key_at <= now + InstituteKey::MAX_ROTATION_GRACE_PERIOD,
+            ValidatorError::InvalidKeyRetirement
+        );
+
+        // An admitted candidate gets the InstituteKey PDA of its own identity: taking it
+        // would make the admission of an open candidate fail on every vote
+        require!(
+            !load_institute_entry(&ctx.accounts.new_key_entry)?.is_some_and(|e| e.is_active()),
+            ValidatorError::KeyAlreadyInUse
+        );
+        require!(
+            !load_election_history(&ctx.accounts.new_key_election_history)?
+                .is_some_and(|h| h.election_open),
+            ValidatorError::KeyAlreadyInUse
+        );
+
+        current_key_record.retired_at = Some(retire_current_key_at);
+
+        new_key_record.key = new_key;
+        new_key_record.institute = entry.institute;
+        new_key_record.permissions = InstituteKey::ALL_PERMISSIONS;
+        new_key_record.added_at = now;
+        new_key_record.retired_at = None;
+        new_key_record.bump = ctx.bumps.new_key_record;
+
+        entry.signing_key = new_key;
+
+        emit!(InstituteKeyRotated {
+            institute: entry.institute,
+            old_key: current_key,
+            new_key,
+            old_key_retired_at: retire_current_key_at,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Register a delegate key that can act for the calling institute
+    /// Delegates are limited to the certificate permissions granted here and
+    /// can never vote, rotate keys or manage the institute
+    pub fn add_institute_delegate(
+        ctx: Context<AddInstituteDelegate>,
+        permissions: u8,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let entry = &ctx.accounts.institute_entry;
+        let delegate_key_record = &mut ctx.accounts.delegate_key_record;
+        let delegate = ctx.accounts.delegate.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        // Only the primary signing key can manage delegates
+        require!(
+            entry.signing_key == ctx.accounts.institute.key(),
+            ValidatorError::NotPrimarySigningKey
+        );
+
+        // Delegates must be granted at least one certificate permission, and nothing more
+        require!(
+            permissions != 0 && permissions & !InstituteKey::DELEGATE_PERMISSIONS == 0,
+            ValidatorError::InvalidDelegatePermissions
+        );
+
+        // Expiry, if any, must lie in the future
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > now, ValidatorError::InvalidDelegateExpiry);
+        }
+
+        delegate_key_record.key = delegate;
+        delegate_key_record.institute = entry.institute;
+        delegate_key_record.permissions = permissions;
+        delegate_key_record.added_at = now;
+        delegate_key_record.retired_at = expires_at;
+        delegate_key_record.bump = ctx.bumps.delegate_key_record;
+
+        emit!(InstituteDelegateAdded {
+            institute: entry.institute,
+            delegate,
+            permissions,
+            expires_at,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Revoke a delegate key of the calling institute with immediate effect
+    /// The key record is kept so past actions remain attributable
+    pub fn revoke_institute_delegate(ctx: Context<RevokeInstituteDelegate>) -> Result<()> {
+        let entry = &ctx.accounts.institute_entry;
+        let delegate_key_record = &mut ctx.accounts.delegate_key_record;
+        let now = Clock::get()?.unix_timestamp;
+
+        // Only the primary signing key can manage delegates
+        require!(
+            entry.signing_key == ctx.accounts.institute.key(),
+            ValidatorError::NotPrimarySigningKey
+        );
+
+        require!(
+            delegate_key_record.is_delegate(),
+            ValidatorError::NotADelegate
+        );
+
+        // Never push back an expiry that already lies in the past
+        if delegate_key_record.is_active(now) {
+            delegate_key_record.retired_at = Some(now);
         }
 
+        emit!(InstituteDelegateRevoked {
+            institute: entry.institute,
+            delegate: delegate_key_record.key,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Update the public profile of the calling institute
+    /// Only active institutes can update their profile
+    pub fn update_institute_profile(
+        ctx: Context<UpdateInstituteProfile>,
+        params: InstituteProfileParams,
+    ) -> Result<()> {
+        let profile = &mut ctx.accounts.institute_profile;
+
+        params.validate()?;
+
+        profile.display_name = params.display_name;
+        profile.country_code = params.country_code;
+        profile.accreditation_body = params.accreditation_body;
+        profile.accreditation_id = params.accreditation_id;
+        profile.uri = params.uri;
+        profile.logo_hash = params.logo_hash;
+        profile.updated_at = Clock::get()?.unix_timestamp;
+
+        emit!(InstituteProfileUpdated {
+            institute: profile.institute,
+            timestamp: profile.updated_at,
+        });
+
         Ok(())
     }
 
@@ -172,12 +642,15 @@ pub mod institute_validator {
         let voting_state = &ctx.accounts.voting_state;
 
         Ok(VotingStateView {
-            candidate_institute: voting_state.candidate_institute,
+            kind: voting_state.kind.clone(),
+            subject: voting_state.subject,
+            round: voting_state.round,
             votes_for_count: voting_state.votes_for.len() as u32,
             votes_against_count: voting_state.votes_against.len() as u32,
             total_eligible_voters: voting_state.total_eligible_voters,
             status: voting_state.status.clone(),
             created_at: voting_state.created_at,
+            voting_ends_at: voting_state.voting_ends_at,
             concluded_at: voting_state.concluded_at,
         })
     }
@@ -189,35 +662,284 @@ pub mod institute_validator {
 
 #[account]
 pub struct InstituteRegistry {
-    /// List of all registered and trusted institute public keys
-    pub registered_institutes: Vec<Pubkey>,
-    /// Authority that can perform administrative actions
-    pub authority: Pubkey,
+    /// Number of currently registered (active) institutes
+    pub institute_count: u32,
+    /// Keys that can jointly perform administrative actions
+    pub authorities: Vec<Pubkey>,
+    /// Number of `authorities` that must sign administrative actions
+    pub authority_threshold: u8,
+    /// Authority set proposed by `propose_authority_transfer`, awaiting acceptance
+    pub pending_transfer: Option<AuthorityTransfer>,
     /// PDA bump seed
     pub bump: u8,
+    /// Keys that can individually pause (but not unpause) the programs
+    pub guardians: Vec<Pubkey>,
+    /// Bitflags of the paused actions (see `InstituteRegistry::PAUSE_*`)
+    pub paused_scopes: u8,
+    /// Admission elections still open; each holds a seat under `MAX_INSTITUTES`
+    pub open_admissions: u32,
 }
 
 impl InstituteRegistry {
-    pub const BASE_LEN: usize = 8 + // discriminator
-        4 + // Vec length prefix
-        32 + // authority
+    /// Largest number of active institutes, counting the seats held by open admissions
+    /// Neither the registry nor the VotingState accounts limit membership: institutes
+    /// live in their own InstituteEntry PDAs and elections grow with realloc as votes
+    /// are cast. The bound is the program heap, see `VotingState::MAX_ELIGIBLE_VOTERS`
+    pub const MAX_INSTITUTES: u32 = VotingState::MAX_ELIGIBLE_VOTERS as u32;
+    /// Largest authority set the registry can hold
+    pub const MAX_AUTHORITIES: usize = 10;
+    /// Largest guardian set the registry can hold
+    pub const MAX_GUARDIANS: usize = 5;
+
+    /// Certificate issuance, correction and renewal
+    pub const PAUSE_ISSUANCE: u8 = 1 << 0;
+    /// Certificate and batch leaf revocation
+    pub const PAUSE_REVOCATION: u8 = 1 << 1;
+    /// Elections, votes and their finalization
+    pub const PAUSE_GOVERNANCE: u8 = 1 << 2;
+    /// Certificate verification views
+    pub const PAUSE_VERIFICATION: u8 = 1 << 3;
+    pub const PAUSE_ALL: u8 = Self::PAUSE_ISSUANCE
+        | Self::PAUSE_REVOCATION
+        | Self::PAUSE_GOVERNANCE
+        | Self::PAUSE_VERIFICATION;
+
+    pub const LEN: usize = 8 + // discriminator
+        4 + // institute_count
+        4 + Self::MAX_AUTHORITIES * 32 + // authorities
+        1 + // authority_threshold
+        1 + AuthorityTransfer::LEN + // pending_transfer (Option)
+        1 + // bump
+        4 + Self::MAX_GUARDIANS * 32 + // guardians
+        1 + // paused_scopes
+        4; // open_admissions
+
+    /// Check if any action in `scope` is paused
+    pub fn is_paused(&self, scope: u8) -> bool {
+        self.paused_scopes & scope != 0
+    }
+
+    /// Check if the signing `authority` and the signers in `additional_signers` meet
+    /// the authority threshold, for programs gating administrative actions on the registry
+    pub fn is_authorized(&self, authority: &Pubkey, additional_signers: &[AccountInfo]) -> bool {
+        count_authority_approvals(&self.authorities, authority, additional_signers)
+            >= self.authority_threshold as usize
+    }
+
+    /// Check if every seat up to MAX_INSTITUTES is taken by a member or an open admission
+    pub fn is_full(&self) -> bool {
+        self.institute_count + self.open_admissions >= Self::MAX_INSTITUTES
+    }
+}
+
+/// Authority set awaiting acceptance by its own members
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AuthorityTransfer {
+    /// Proposed authority keys
+    pub authorities: Vec<Pubkey>,
+    /// Proposed signing threshold
+    pub threshold: u8,
+    /// Timestamp when the transfer was proposed
+    pub proposed_at: i64,
+}
+
+impl AuthorityTransfer {
+    pub const LEN: usize = 4 + InstituteRegistry::MAX_AUTHORITIES * 32 + // authorities
+        1 + // threshold
+        8; // proposed_at
+}
+
+/// Governance parameters, stored at `[b"governance_config"]`
+/// Set at registry initialization and only changed through a governance election
+#[account]
+pub struct GovernanceConfig {
+    /// Share of votes cast that must be in favour, in basis points (10_000 = 100%)
+    pub approval_threshold_bps: u16,
+    /// Share of eligible voters that must cast a vote, in basis points
+    pub quorum_bps: u16,
+    /// Shortest voting period an election can be created with
+    pub min_voting_period: i64,
+    /// How verifiers treat certificates of removed institutes
+    pub removed_issuer_policy: RemovedIssuerPolicy,
+    /// Timestamp when the parameters were last set
+    pub updated_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GovernanceConfig {
+    pub const LEN: usize = 8 + // discriminator
+        2 + // approval_threshold_bps
+        2 + // quorum_bps
+        8 + // min_voting_period
+        1 + // removed_issuer_policy (enum)
+        8 + // updated_at
+        1; // bump
+
+    /// Overwrite the governance parameters
+    pub fn apply(&mut self, params: &GovernanceParams, now: i64) {
+        self.approval_threshold_bps = params.approval_threshold_bps;
+        self.quorum_bps = params.quorum_bps;
+        self.min_voting_period = params.min_voting_period;
+        self.removed_issuer_policy = params.removed_issuer_policy.clone();
+        self.updated_at = now;
+    }
+}
+
+/// Registry entry for a single institute, stored at `[b"institute", institute]`
+/// `institute` is the stable identity: the key the institute was admitted with
+#[account]
+pub struct InstituteEntry {
+    /// Public key of the institute
+    pub institute: Pubkey,
+    /// Current primary signing key (equals `institute` until the first rotation)
+    pub signing_key: Pubkey,
+    /// Current membership status
+    pub status: InstituteStatus,
+    /// Timestamp when the institute joined (or last re-joined) the registry
+    pub joined_at: i64,
+    /// Timestamp when the institute was removed (if applicable)
+    pub removed_at: Option<i64>,
+    /// VotingState that admitted the institute (None for founding institutes)
+    pub admitted_via: Option<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InstituteEntry {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // institute
+        32 + // signing_key
+        1 + // status (enum)
+        8 + // joined_at
+        1 + 8 + // removed_at (Option)
+        1 + 32 + // admitted_via (Option)
+        1; // bump
+
+    /// Check if the institute is currently registered
+    pub fn is_active(&self) -> bool {
+        self.status == InstituteStatus::Active
+    }
+}
+
+/// Signing key record, stored at `[b"institute_key", key]`
+/// Maps any key an institute has signed with back to its stable identity
+/// Covers both the institute's own signing keys and its delegate keys
+#[account]
+pub struct InstituteKey {
+    /// The signing key
+    pub key: Pubkey,
+    /// Identity of the institute owning the key
+    pub institute: Pubkey,
+    /// Bitflags of the actions the key may perform (see `InstituteKey::PERMISSION_*`)
+    pub permissions: u8,
+    /// Timestamp when the key was registered
+    pub added_at: i64,
+    /// Timestamp from which the key can no longer act for the institute
+    /// (rotation, delegate expiry or delegate revocation)
+    pub retired_at: Option<i64>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InstituteKey {
+    /// Issue new certificates and batches
+    pub const PERMISSION_ISSUE: u8 = 1 << 0;
+    /// Correct certificates and extend their expiry
+    pub const PERMISSION_CORRECT: u8 = 1 << 1;
+    /// Revoke certificates and batch leaves
+    pub const PERMISSION_REVOKE: u8 = 1 << 2;
+    /// Vote, rotate keys, manage delegates and the profile (signing keys only)
+    pub const PERMISSION_GOVERN: u8 = 1 << 3;
+
+    /// Permissions that can be granted to a delegate
+    pub const DELEGATE_PERMISSIONS: u8 =
+        Self::PERMISSION_ISSUE | Self::PERMISSION_CORRECT | Self::PERMISSION_REVOKE;
+    /// Permissions of the institute's own signing keys
+    pub const ALL_PERMISSIONS: u8 = Self::DELEGATE_PERMISSIONS | Self::PERMISSION_GOVERN;
+
+    /// Longest time a rotated-out key may keep signing (30 days)
+    pub const MAX_ROTATION_GRACE_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // key
+        32 + // institute
+        1 + // permissions
+        8 + // added_at
+        1 + 8 + // retired_at (Option)
         1; // bump
 
-    /// Calculate space needed for n institutes
-    pub fn space(num_institutes: usize) -> usize {
-        Self::BASE_LEN + (num_institutes * 32)
+    /// Check if the key can still act for its institute
+    pub fn is_active(&self, now: i64) -> bool {
+        self.retired_at.is_none_or(|retired_at| now < retired_at)
+    }
+
+    /// Check if the key was granted every permission in `permission`
+    pub fn has_permission(&self, permission: u8) -> bool {
+        self.permissions & permission == permission
     }
 
-    /// Check if an institute is registered
-    pub fn is_institute_registered(&self, institute: &Pubkey) -> bool {
-        self.registered_institutes.contains(institute)
+    /// Check if the key is a delegate rather than a signing key of the institute
+    pub fn is_delegate(&self) -> bool {
+        !self.has_permission(Self::PERMISSION_GOVERN)
     }
 }
 
+/// Public profile of an institute, stored at `[b"institute_profile", institute]`
+/// Created empty on admission and filled in by the institute itself
+#[account]
+pub struct InstituteProfile {
+    /// Public key of the institute
+    pub institute: Pubkey,
+    /// Human-readable name shown to verifiers
+    pub display_name: String,
+    /// ISO 3166-1 alpha-2 country code (zeroed if unset)
+    pub country_code: [u8; 2],
+    /// Name of the accrediting body
+    pub accreditation_body: String,
+    /// Identifier assigned by the accrediting body
+    pub accreditation_id: String,
+    /// Website or DID URI of the institute
+    pub uri: String,
+    /// SHA-256 hash of the institute logo
+    pub logo_hash: [u8; 32],
+    /// Timestamp of the last profile update (0 if never updated)
+    pub updated_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InstituteProfile {
+    pub const MAX_DISPLAY_NAME_LEN: usize = 64;
+    pub const MAX_ACCREDITATION_BODY_LEN: usize = 64;
+    pub const MAX_ACCREDITATION_ID_LEN: usize = 32;
+    pub const MAX_URI_LEN: usize = 128;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // institute
+        4 + Self::MAX_DISPLAY_NAME_LEN + // display_name
+        2 + // country_code
+        4 + Self::MAX_ACCREDITATION_BODY_LEN + // accreditation_body
+        4 + Self::MAX_ACCREDITATION_ID_LEN + // accreditation_id
+        4 + Self::MAX_URI_LEN + // uri
+        32 + // logo_hash
+        8 + // updated_at
+        1; // bump
+}
+
+/// Election state, stored at `[kind.seed_prefix(), subject, round]`
+/// Closed by `close_election` once concluded, refunding the proposer
 #[account]
 pub struct VotingState {
-    /// Candidate institute seeking admission
-    pub candidate_institute: Pubkey,
+    /// What is being voted on
+    pub kind: ElectionKind,
+    /// Candidate institute for admissions, institute under review for removals,
+    /// GovernanceConfig PDA for governance changes
+    pub subject: Pubkey,
+    /// Round of the election among all elections of this kind on `subject`
+    pub round: u32,
+    /// Account that paid for the election and is refunded when it is closed
+    pub proposer: Pubkey,
     /// List of institutes that voted for
     pub votes_for: Vec<Pubkey>,
     /// List of institutes that voted against
@@ -226,8 +948,10 @@ pub struct VotingState {
     pub total_eligible_voters: u32,
     /// Current status of the voting
     pub status: VotingStatus,
-    /// Timestamp when voting was created
+    /// Timestamp when voting was created; only institutes that joined by then can vote
     pub created_at: i64,
+    /// Timestamp from which votes are no longer accepted
+    pub voting_ends_at: i64,
     /// Timestamp when voting concluded (if applicable)
     pub concluded_at: Option<i64>,
     /// PDA bump seed
@@ -235,19 +959,35 @@ pub struct VotingState {
 }
 
 impl VotingState {
+    /// Lowest minimum voting period the governance config may set (1 hour)
+    pub const MIN_VOTING_PERIOD: i64 = 60 * 60;
+    /// Longest voting period an election can be created with (30 days)
+    pub const MAX_VOTING_PERIOD: i64 = 30 * 24 * 60 * 60;
+    /// Largest electorate an election can have
+    /// The account is created empty and grown by each vote, so its size is not the
+    /// limit: every vote instruction deserializes both vote lists onto the 32 KiB
+    /// program heap, where a list past 256 voters already takes 28 KiB
+    pub const MAX_ELIGIBLE_VOTERS: usize = 300;
+
     pub const BASE_LEN: usize = 8 + // discriminator
-        32 + // candidate_institute
+        1 + GovernanceParams::LEN + // kind (enum, largest variant)
+        32 + // subject
+        4 + // round
+        32 + // proposer
         4 + // votes_for Vec prefix
         4 + // votes_against Vec prefix
         4 + // total_eligible_voters
         1 + 1 + // status (enum)
         8 + // created_at
+        8 + // voting_ends_at
         1 + 8 + // concluded_at (Option)
         1; // bump
 
-    /// Calculate space needed for n voters
+    /// Calculate space needed for n votes
+    /// Each voter appears once across the for and against lists, so the account is
+    /// created with `space(0)` and grown by one key with every vote
     pub fn space(num_voters: usize) -> usize {
-        Self::BASE_LEN + (num_voters * 32 * 2) // Both for and against lists
+        Self::BASE_LEN + num_voters * 32
     }
 
     /// Check if a voter has already cast their vote
@@ -255,30 +995,138 @@ impl VotingState {
         self.votes_for.contains(voter) || self.votes_against.contains(voter)
     }
 
-    /// Calculate approval percentage
+    /// Calculate approval percentage of the votes cast, in basis points (10_000 = 100%)
     pub fn approval_percentage(&self) -> u32 {
         let total_votes = self.votes_for.len() + self.votes_against.len();
         if total_votes == 0 {
             return 0;
         }
-        ((self.votes_for.len() * 100) / total_votes) as u32
+        ((self.votes_for.len() * 10_000) / total_votes) as u32
+    }
+
+    /// Check if enough eligible voters have voted to meet the quorum
+    pub fn quorum_reached(&self, quorum_bps: u16) -> bool {
+        let total_votes = (self.votes_for.len() + self.votes_against.len()) as u64;
+        total_votes * 10_000 >= quorum_bps as u64 * self.total_eligible_voters as u64
+    }
+
+    /// Decide the election on the votes cast so far
+    pub fn outcome(&self, config: &GovernanceConfig) -> VotingStatus {
+        if !self.quorum_reached(config.quorum_bps) {
+            VotingStatus::Expired
+        } else if self.approval_percentage() >= config.approval_threshold_bps as u32 {
+            VotingStatus::Approved
+        } else {
+            VotingStatus::Rejected
+        }
     }
 }
 
+/// Record of every election of one kind on one subject, stored at
+/// `[b"election_history", kind.seed_prefix(), subject]`
+/// Outlives the VotingState accounts so results survive `close_election`
+#[account]
+pub struct ElectionHistory {
+    /// Subject of the elections
+    pub subject: Pubkey,
+    /// Number of elections started; the round of the next election
+    pub rounds: u32,
+    /// Whether the latest round is still being voted on
+    pub election_open: bool,
+    /// Result of the latest concluded round
+    pub last_result: Option<ElectionResult>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ElectionHistory {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // subject
+        4 + // rounds
+        1 + // election_open
+        1 + ElectionResult::LEN + // last_result (Option)
+        1; // bump
+}
+
+/// Compact outcome of a concluded election
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ElectionResult {
+    /// Round of the election
+    pub round: u32,
+    /// Final status (Approved, Rejected or Expired)
+    pub status: VotingStatus,
+    /// Number of votes in favour
+    pub votes_for: u32,
+    /// Number of votes against
+    pub votes_against: u32,
+    /// Total number of eligible voters at election start
+    pub total_eligible_voters: u32,
+    /// Timestamp when the election concluded
+    pub concluded_at: i64,
+}
+
+impl ElectionResult {
+    pub const LEN: usize = 4 + // round
+        1 + // status (enum)
+        4 + // votes_for
+        4 + // votes_against
+        4 + // total_eligible_voters
+        8; // concluded_at
+}
+
 // ============================================================================
 // Enums
 // ============================================================================
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum VotingStatus {
+pub enum InstituteStatus {
     Active,
-    Approved,
-    Rejected,
+    Removed,
 }
 
-// ============================================================================
-// Context Structures
-// ============================================================================
+/// How `certificate_system` reports certificates whose issuer was removed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum RemovedIssuerPolicy {
+    /// Certificates issued before the removal stay valid
+    Grandfather,
+    /// Every certificate of a removed issuer is flagged
+    FlagAll,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ElectionKind {
+    /// Admit `subject` as a new institute
+    Admission,
+    /// Remove `subject` from the registry
+    Removal,
+    /// Replace the governance parameters with the proposed ones
+    GovernanceChange(GovernanceParams),
+}
+
+impl ElectionKind {
+    /// First seed of the VotingState PDA (and second of the ElectionHistory PDA),
+    /// keeping elections of different kinds apart
+    pub fn seed_prefix(&self) -> &'static [u8] {
+        match self {
+            ElectionKind::Admission => b"voting_state",
+            ElectionKind::Removal => b"removal_election",
+            ElectionKind::GovernanceChange(_) => b"governance_election",
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum VotingStatus {
+    Active,
+    Approved,
+    Rejected,
+    /// Voting period ended without the required outcome
+    Expired,
+}
+
+// ============================================================================
+// Context Structures
+// ============================================================================
 
 #[derive(Accounts)]
 #[instruction(initial_institutes: Vec<Pubkey>)]
@@ -286,12 +1134,21 @@ pub struct InitializeRegistry<'info> {
     #[account(
         init,
         payer = authority,
-        space = InstituteRegistry::space(initial_institutes.len() + 50), // Extra space for future
+        space = InstituteRegistry::LEN,
         seeds = [b"institute_registry"],
         bump
     )]
     pub institute_registry: Account<'info, InstituteRegistry>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = GovernanceConfig::LEN,
+        seeds = [b"governance_config"],
+        bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -301,22 +1158,108 @@ pub struct InitializeRegistry<'info> {
 #[derive(Accounts)]
 #[instruction(candidate_institute: Pubkey)]
 pub struct NewInstituteElection<'info> {
+    #[account(
+        mut,
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump,
+        realloc = InstituteRegistry::LEN,
+        realloc::payer = proposer,
+        realloc::zero = false
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        space = ElectionHistory::LEN,
+        seeds = [b"election_history", b"voting_state".as_ref(), candidate_institute.as_ref()],
+        bump
+    )]
+    pub election_history: Account<'info, ElectionHistory>,
+
     #[account(
         init,
         payer = proposer,
-        space = VotingState::space(50), // Space for up to 50 voters
-        seeds = [b"voting_state", candidate_institute.as_ref()],
+        space = VotingState::space(0),
+        seeds = [
+            b"voting_state",
+            candidate_institute.as_ref(),
+            election_history.rounds.to_le_bytes().as_ref()
+        ],
         bump
     )]
     pub voting_state: Account<'info, VotingState>,
 
+    #[account(
+        seeds = [b"governance_config"],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// InstituteEntry PDA of the candidate (may not exist yet)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(
+        seeds = [b"institute", candidate_institute.as_ref()],
+        bump
+    )]
+    pub candidate_entry: UncheckedAccount<'info>,
+
+    /// InstituteKey PDA of the candidate key (may not exist yet)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(
+        seeds = [b"institute_key", candidate_institute.as_ref()],
+        bump
+    )]
+    pub candidate_key: UncheckedAccount<'info>,
+
+    /// Any account can propose (or restrict to registered institutes if needed)
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct NewGovernanceElection<'info> {
     #[account(
         seeds = [b"institute_registry"],
         bump = institute_registry.bump
     )]
     pub institute_registry: Account<'info, InstituteRegistry>,
 
-    /// Any account can propose (or restrict to registered institutes if needed)
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        space = ElectionHistory::LEN,
+        seeds = [
+            b"election_history",
+            b"governance_election".as_ref(),
+            governance_config.key().as_ref()
+        ],
+        bump
+    )]
+    pub election_history: Account<'info, ElectionHistory>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = VotingState::space(0),
+        seeds = [
+            b"governance_election",
+            governance_config.key().as_ref(),
+            election_history.rounds.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub voting_state: Account<'info, VotingState>,
+
+    #[account(
+        seeds = [b"governance_config"],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Any account can propose a governance change
     #[account(mut)]
     pub proposer: Signer<'info>,
 
@@ -327,12 +1270,32 @@ pub struct NewInstituteElection<'info> {
 pub struct Vote<'info> {
     #[account(
         mut,
-        seeds = [b"voting_state", voting_state.candidate_institute.as_ref()],
+        seeds = [
+            voting_state.kind.seed_prefix(),
+            voting_state.subject.as_ref(),
+            voting_state.round.to_le_bytes().as_ref()
+        ],
         bump = voting_state.bump,
-        constraint = voting_state.status == VotingStatus::Active @ ValidatorError::VotingNotActive
+        constraint = voting_state.status == VotingStatus::Active @ ValidatorError::VotingNotActive,
+        realloc = VotingState::space(
+            voting_state.votes_for.len() + voting_state.votes_against.len() + 1
+        ),
+        realloc::payer = voter,
+        realloc::zero = false
     )]
     pub voting_state: Account<'info, VotingState>,
 
+    #[account(
+        mut,
+        seeds = [
+            b"election_history",
+            voting_state.kind.seed_prefix(),
+            voting_state.subject.as_ref()
+        ],
+        bump = election_history.bump
+    )]
+    pub election_history: Account<'info, ElectionHistory>,
+
     #[account(
         mut,
         seeds = [b"institute_registry"],
@@ -340,12 +1303,224 @@ pub struct Vote<'info> {
     )]
     pub institute_registry: Account<'info, InstituteRegistry>,
 
+    #[account(
+        mut,
+        seeds = [b"governance_config"],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// InstituteKey PDA of the voter's signing key
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(
+        seeds = [b"institute_key", voter.key().as_ref()],
+        bump
+    )]
+    pub voter_key: UncheckedAccount<'info>,
+
+    /// InstituteEntry PDA of the voter's institute
+    /// CHECK: PDA validation happens in instruction logic
+    pub voter_entry: UncheckedAccount<'info>,
+
+    /// InstituteEntry PDA of the subject (admission and removal elections only)
+    /// CHECK: Seeds are verified; contents are written in instruction logic
+    #[account(
+        mut,
+        seeds = [b"institute", voting_state.subject.as_ref()],
+        bump
+    )]
+    pub subject_entry: Option<UncheckedAccount<'info>>,
+
+    /// InstituteProfile PDA of the subject (admission elections only)
+    /// CHECK: Seeds are verified; contents are written in instruction logic
+    #[account(
+        mut,
+        seeds = [b"institute_profile", voting_state.subject.as_ref()],
+        bump
+    )]
+    pub subject_profile: Option<UncheckedAccount<'info>>,
+
+    /// InstituteKey PDA of the subject's key (admission elections only)
+    /// CHECK: Seeds are verified; contents are written in instruction logic
+    #[account(
+        mut,
+        seeds = [b"institute_key", voting_state.subject.as_ref()],
+        bump
+    )]
+    pub subject_key: Option<UncheckedAccount<'info>>,
+
     /// Must be a registered institute to vote
+    /// Pays for the candidate's accounts if this vote admits them
+    #[account(mut)]
     pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeElection<'info> {
+    #[account(
+        mut,
+        seeds = [
+            voting_state.kind.seed_prefix(),
+            voting_state.subject.as_ref(),
+            voting_state.round.to_le_bytes().as_ref()
+        ],
+        bump = voting_state.bump,
+        constraint = voting_state.status == VotingStatus::Active @ ValidatorError::VotingNotActive
+    )]
+    pub voting_state: Account<'info, VotingState>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"election_history",
+            voting_state.kind.seed_prefix(),
+            voting_state.subject.as_ref()
+        ],
+        bump = election_history.bump
+    )]
+    pub election_history: Account<'info, ElectionHistory>,
+
+    #[account(
+        mut,
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"governance_config"],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// InstituteEntry PDA of the subject (admission and removal elections only)
+    /// CHECK: Seeds are verified; contents are written in instruction logic
+    #[account(
+        mut,
+        seeds = [b"institute", voting_state.subject.as_ref()],
+        bump
+    )]
+    pub subject_entry: Option<UncheckedAccount<'info>>,
+
+    /// InstituteProfile PDA of the subject (admission elections only)
+    /// CHECK: Seeds are verified; contents are written in instruction logic
+    #[account(
+        mut,
+        seeds = [b"institute_profile", voting_state.subject.as_ref()],
+        bump
+    )]
+    pub subject_profile: Option<UncheckedAccount<'info>>,
+
+    /// InstituteKey PDA of the subject's key (admission elections only)
+    /// CHECK: Seeds are verified; contents are written in instruction logic
+    #[account(
+        mut,
+        seeds = [b"institute_key", voting_state.subject.as_ref()],
+        bump
+    )]
+    pub subject_key: Option<UncheckedAccount<'info>>,
+
+    /// Anyone can finalize; pays for the candidate's accounts on admission
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
+pub struct CloseElection<'info> {
+    #[account(
+        mut,
+        seeds = [
+            voting_state.kind.seed_prefix(),
+            voting_state.subject.as_ref(),
+            voting_state.round.to_le_bytes().as_ref()
+        ],
+        bump = voting_state.bump,
+        has_one = proposer,
+        constraint = voting_state.status != VotingStatus::Active @ ValidatorError::ElectionStillActive,
+        close = proposer
+    )]
+    pub voting_state: Account<'info, VotingState>,
+
+    /// Proposer of the election, refunded the rent
+    /// CHECK: Verified against `voting_state.proposer`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(institute_to_remove: Pubkey)]
 pub struct RemoveInstitute<'info> {
+    #[account(
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ElectionHistory::LEN,
+        seeds = [
+            b"election_history",
+            b"removal_election".as_ref(),
+            institute_to_remove.as_ref()
+        ],
+        bump
+    )]
+    pub election_history: Account<'info, ElectionHistory>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = VotingState::space(0),
+        seeds = [
+            b"removal_election",
+            institute_to_remove.as_ref(),
+            election_history.rounds.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub voting_state: Account<'info, VotingState>,
+
+    #[account(
+        seeds = [b"governance_config"],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        seeds = [b"institute", institute_to_remove.as_ref()],
+        bump = institute_entry.bump,
+        constraint = institute_entry.is_active() @ ValidatorError::InstituteNotFound
+    )]
+    pub institute_entry: Account<'info, InstituteEntry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// One of the current registry authorities
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthorityTransfer<'info> {
     #[account(
         mut,
         seeds = [b"institute_registry"],
@@ -353,14 +1528,684 @@ pub struct RemoveInstitute<'info> {
     )]
     pub institute_registry: Account<'info, InstituteRegistry>,
 
+    /// One of the proposed registry authorities
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    #[account(
+        mut,
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// One of the registry authorities
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPausedScopes<'info> {
+    #[account(
+        mut,
+        seeds = [b"institute_registry"],
+        bump = institute_registry.bump
+    )]
+    pub institute_registry: Account<'info, InstituteRegistry>,
+
+    /// A guardian, or one of the registry authorities
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RotateInstituteKey<'info> {
+    #[account(
+        mut,
+        seeds = [b"institute", current_key_record.institute.as_ref()],
+        bump = institute_entry.bump,
+        constraint = institute_entry.is_active() @ ValidatorError::InstituteNotFound
+    )]
+    pub institute_entry: Account<'info, InstituteEntry>,
+
+    #[account(
+        mut,
+        seeds = [b"institute_key", current_key.key().as_ref()],
+        bump = current_key_record.bump
+    )]
+    pub current_key_record: Account<'info, InstituteKey>,
+
+    #[account(
+        init,
+        payer = current_key,
+        space = InstituteKey::LEN,
+        seeds = [b"institute_key", new_key.key().as_ref()],
+        bump
+    )]
+    pub new_key_record: Account<'info, InstituteKey>,
+
+    /// Current primary signing key of the institute
+    #[account(mut)]
+    pub current_key: Signer<'info>,
+
+    /// New signing key (must sign to prove possession)
+    pub new_key: Signer<'info>,
+
+    /// InstituteEntry PDA of the new key as an institute identity (may not exist)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(
+        seeds = [b"institute", new_key.key().as_ref()],
+        bump
+    )]
+    pub new_key_entry: UncheckedAccount<'info>,
+
+    /// Admission ElectionHistory PDA of the new key as a candidate (may not exist)
+    /// CHECK: Seeds are verified; contents are checked in instruction logic
+    #[account(
+        seeds = [b"election_history", b"voting_state".as_ref(), new_key.key().as_ref()],
+        bump
+    )]
+    pub new_key_election_history: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddInstituteDelegate<'info> {
+    #[account(
+        seeds = [b"institute", institute_key.institute.as_ref()],
+        bump = institute_entry.bump,
+        constraint = institute_entry.is_active() @ ValidatorError::InstituteNotFound
+    )]
+    pub institute_entry: Account<'info, InstituteEntry>,
+
+    #[account(
+        seeds = [b"institute_key", institute.key().as_ref()],
+        bump = institute_key.bump
+    )]
+    pub institute_key: Account<'info, InstituteKey>,
+
+    #[account(
+        init,
+        payer = institute,
+        space = InstituteKey::LEN,
+        seeds = [b"institute_key", delegate.key().as_ref()],
+        bump
+    )]
+    pub delegate_key_record: Account<'info, InstituteKey>,
+
+    /// Primary signing key of the institute
+    #[account(mut)]
+    pub institute: Signer<'info>,
+
+    /// Delegate key (must sign to prove possession)
+    pub delegate: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeInstituteDelegate<'info> {
+    #[account(
+        seeds = [b"institute", delegate_key_record.institute.as_ref()],
+        bump = institute_entry.bump
+    )]
+    pub institute_entry: Account<'info, InstituteEntry>,
+
+    #[account(
+        mut,
+        seeds = [b"institute_key", delegate_key_record.key.as_ref()],
+        bump = delegate_key_record.bump
+    )]
+    pub delegate_key_record: Account<'info, InstituteKey>,
+
+    /// Primary signing key of the institute owning the delegate
+    pub institute: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateInstituteProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"institute_profile", institute_key.institute.as_ref()],
+        bump = institute_profile.bump
+    )]
+    pub institute_profile: Account<'info, InstituteProfile>,
+
+    #[account(
+        seeds = [b"institute", institute_key.institute.as_ref()],
+        bump = institute_entry.bump,
+        constraint = institute_entry.is_active() @ ValidatorError::InstituteNotFound
+    )]
+    pub institute_entry: Account<'info, InstituteEntry>,
+
+    #[account(
+        seeds = [b"institute_key", institute.key().as_ref()],
+        bump = institute_key.bump,
+        constraint = institute_key.is_active(Clock::get()?.unix_timestamp) @ ValidatorError::KeyRetired,
+        constraint = !institute_key.is_delegate() @ ValidatorError::MissingKeyPermission
+    )]
+    pub institute_key: Account<'info, InstituteKey>,
+
+    /// Signing key of the institute updating its own profile
+    pub institute: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct GetVotingState<'info> {
     pub voting_state: Account<'info, VotingState>,
 }
 
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Deserialize an InstituteEntry PDA, returning None if it was never created
+fn load_institute_entry(entry_info: &AccountInfo) -> Result<Option<InstituteEntry>> {
+    if entry_info.data_is_empty() {
+        return Ok(None);
+    }
+    require!(
+        entry_info.owner == &crate::ID,
+        ValidatorError::InstituteEntryMismatch
+    );
+    Ok(Some(InstituteEntry::try_deserialize(
+        &mut &entry_info.data.borrow()[..],
+    )?))
+}
+
+/// Deserialize an InstituteKey PDA, returning None if it was never created
+fn load_institute_key(key_info: &AccountInfo) -> Result<Option<InstituteKey>> {
+    if key_info.data_is_empty() {
+        return Ok(None);
+    }
+    require!(
+        key_info.owner == &crate::ID,
+        ValidatorError::InstituteEntryMismatch
+    );
+    Ok(Some(InstituteKey::try_deserialize(
+        &mut &key_info.data.borrow()[..],
+    )?))
+}
+
+/// Deserialize an ElectionHistory PDA, returning None if no election was ever started
+fn load_election_history(history_info: &AccountInfo) -> Result<Option<ElectionHistory>> {
+    if history_info.data_is_empty() {
+        return Ok(None);
+    }
+    require!(
+        history_info.owner == &crate::ID,
+        ValidatorError::InstituteEntryMismatch
+    );
+    Ok(Some(ElectionHistory::try_deserialize(
+        &mut &history_info.data.borrow()[..],
+    )?))
+}
+
+/// Resolve a signing key to the entry of an active institute
+/// Returns None if the key is unknown, retired or a delegate, or the institute is not active
+fn resolve_active_institute(
+    key_info: &AccountInfo,
+    entry_info: &AccountInfo,
+    now: i64,
+) -> Result<Option<InstituteEntry>> {
+    let Some(key) = load_institute_key(key_info)? else {
+        return Ok(None);
+    };
+    if !key.is_active(now) || key.is_delegate() {
+        return Ok(None);
+    }
+
+    let (expected_pda, _bump) =
+        Pubkey::find_program_address(&[b"institute", key.institute.as_ref()], &crate::ID);
+    require!(
+        entry_info.key() == expected_pda,
+        ValidatorError::InstituteEntryMismatch
+    );
+
+    Ok(load_institute_entry(entry_info)?.filter(|entry| entry.is_active()))
+}
+
+/// Require at least `threshold` distinct members of `authorities` among the
+/// signing `authority` and the signers in `additional_signers`
+fn require_authority_threshold(
+    authorities: &[Pubkey],
+    threshold: u8,
+    authority: &Pubkey,
+    additional_signers: &[AccountInfo],
+) -> Result<()> {
+    require!(
+        count_authority_approvals(authorities, authority, additional_signers)
+            >= threshold as usize,
+        ValidatorError::AuthorityThresholdNotMet
+    );
+    Ok(())
+}
+
+/// Number of distinct members of `authorities` among the signing `authority` and
+/// the signers in `additional_signers`
+pub fn count_authority_approvals(
+    authorities: &[Pubkey],
+    authority: &Pubkey,
+    additional_signers: &[AccountInfo],
+) -> usize {
+    let signers = std::iter::once(authority).chain(
+        additional_signers
+            .iter()
+            .filter(|info| info.is_signer)
+            .map(|info| info.key),
+    );
+
+    let mut approvals: Vec<Pubkey> = Vec::new();
+    for signer in signers.filter(|key| authorities.contains(key)) {
+        if !approvals.contains(signer) {
+            approvals.push(*signer);
+        }
+    }
+    approvals.len()
+}
+
+/// Check an authority set is non-empty, bounded, free of duplicates and has a
+/// reachable threshold
+fn validate_authority_set(authorities: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !authorities.is_empty() && authorities.len() <= InstituteRegistry::MAX_AUTHORITIES,
+        ValidatorError::InvalidAuthoritySet
+    );
+    require!(
+        threshold >= 1 && threshold as usize <= authorities.len(),
+        ValidatorError::InvalidAuthoritySet
+    );
+    require!(
+        authorities
+            .iter()
+            .enumerate()
+            .all(|(i, authority)| !authorities[..i].contains(authority)),
+        ValidatorError::InvalidAuthoritySet
+    );
+    Ok(())
+}
+
+/// Open the next round of elections on `subject`, with the voting period checked
+/// against the governance config
+fn start_election(
+    voting_state: &mut VotingState,
+    history: &mut ElectionHistory,
+    kind: ElectionKind,
+    subject: Pubkey,
+    total_eligible_voters: u32,
+    governance_config: &GovernanceConfig,
+    voting_period: i64,
+) -> Result<()> {
+    require!(
+        (governance_config.min_voting_period..=VotingState::MAX_VOTING_PERIOD)
+            .contains(&voting_period),
+        ValidatorError::InvalidVotingPeriod
+    );
+
+    // Only one election of a kind can run on a subject at a time
+    require!(!history.election_open, ValidatorError::ElectionAlreadyOpen);
+
+    let now = Clock::get()?.unix_timestamp;
+    voting_state.kind = kind;
+    voting_state.subject = subject;
+    voting_state.round = history.rounds;
+    voting_state.votes_for = Vec::new();
+    voting_state.votes_against = Vec::new();
+    voting_state.total_eligible_voters = total_eligible_voters;
+    voting_state.status = VotingStatus::Active;
+    voting_state.created_at = now;
+    voting_state.voting_ends_at = now + voting_period;
+    voting_state.concluded_at = None;
+
+    history.subject = subject;
+    history.rounds += 1;
+    history.election_open = true;
+
+    emit!(ElectionCreated {
+        kind: voting_state.kind.clone(),
+        subject,
+        round: voting_state.round,
+        eligible_voters: total_eligible_voters,
+        voting_ends_at: voting_state.voting_ends_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Record the outcome of an election in it and its history and, if approved, apply it
+/// Admissions need the subject's entry, profile and key accounts; removals its entry
+#[allow(clippy::too_many_arguments)]
+fn conclude_election<'info>(
+    voting_state: &mut Account<'info, VotingState>,
+    outcome: VotingStatus,
+    history: &mut ElectionHistory,
+    registry: &mut InstituteRegistry,
+    governance_config: &mut GovernanceConfig,
+    subject_entry: Option<&AccountInfo<'info>>,
+    subject_profile: Option<&AccountInfo<'info>>,
+    subject_key: Option<&AccountInfo<'info>>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    now: i64,
+) -> Result<()> {
+    let votes_for = voting_state.votes_for.len() as u32;
+    let votes_against = voting_state.votes_against.len() as u32;
+
+    // The seat held by an admission becomes a member or is freed
+    if voting_state.kind == ElectionKind::Admission {
+        registry.open_admissions -= 1;
+    }
+
+    match outcome {
+        VotingStatus::Approved => match &voting_state.kind {
+            ElectionKind::Admission => {
+                let (Some(entry_info), Some(profile_info), Some(key_info)) =
+                    (subject_entry, subject_profile, subject_key)
+                else {
+                    return err!(ValidatorError::MissingSubjectAccounts);
+                };
+                admit_institute(
+                    &voting_state.subject,
+                    Some(voting_state.key()),
+                    now,
+                    entry_info,
+                    profile_info,
+                    key_info,
+                    payer,
+                    system_program,
+                )?;
+                registry.institute_count += 1;
+
+                emit!(InstituteAdmitted {
+                    candidate: voting_state.subject,
+                    total_institutes: registry.institute_count,
+                    timestamp: now,
+                });
+            }
+            ElectionKind::Removal => {
+                let entry_info = subject_entry.ok_or(ValidatorError::MissingSubjectAccounts)?;
+                remove_institute_entry(entry_info, now)?;
+                registry.institute_count -= 1;
+
+                emit!(InstituteRemoved {
+                    institute: voting_state.subject,
+                    timestamp: now,
+                });
+            }
+            ElectionKind::GovernanceChange(params) => {
+                governance_config.apply(params, now);
+
+                emit!(GovernanceConfigUpdated {
+                    approval_threshold_bps: params.approval_threshold_bps,
+                    quorum_bps: params.quorum_bps,
+                    min_voting_period: params.min_voting_period,
+                    removed_issuer_policy: params.removed_issuer_policy.clone(),
+                    timestamp: now,
+                });
+            }
+        },
+        VotingStatus::Rejected => {
+            emit!(ElectionRejected {
+                subject: voting_state.subject,
+                votes_for,
+                votes_against,
+                timestamp: now,
+            });
+        }
+        VotingStatus::Expired => {
+            emit!(ElectionExpired {
+                subject: voting_state.subject,
+                votes_for,
+                total_eligible_voters: voting_state.total_eligible_voters,
+                timestamp: now,
+            });
+        }
+        VotingStatus::Active => return Ok(()),
+    }
+
+    history.election_open = false;
+    history.last_result = Some(ElectionResult {
+        round: voting_state.round,
+        status: outcome.clone(),
+        votes_for,
+        votes_against,
+        total_eligible_voters: voting_state.total_eligible_voters,
+        concluded_at: now,
+    });
+
+    voting_state.status = outcome;
+    voting_state.concluded_at = Some(now);
+
+    Ok(())
+}
+
+/// Create or reactivate every account that makes `institute` a registry member
+#[allow(clippy::too_many_arguments)]
+fn admit_institute<'info>(
+    institute: &Pubkey,
+    admitted_via: Option<Pubkey>,
+    now: i64,
+    entry_info: &AccountInfo<'info>,
+    profile_info: &AccountInfo<'info>,
+    key_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    write_institute_entry(entry_info, institute, admitted_via, now, payer, system_program)?;
+    create_institute_profile(profile_info, institute, payer, system_program)?;
+    create_institute_key(key_info, institute, now, payer, system_program)
+}
+
+/// Mark an institute as removed; the entry is kept as a historical record
+fn remove_institute_entry(entry_info: &AccountInfo, now: i64) -> Result<()> {
+    let mut entry = load_institute_entry(entry_info)?
+        .filter(|entry| entry.is_active())
+        .ok_or(ValidatorError::InstituteNotFound)?;
+
+    entry.status = InstituteStatus::Removed;
+    entry.removed_at = Some(now);
+    entry.try_serialize(&mut &mut entry_info.data.borrow_mut()[..])
+}
+
+/// Create the InstituteEntry PDA for `institute`, or reactivate it if the
+/// institute was previously removed
+fn write_institute_entry<'info>(
+    entry_info: &AccountInfo<'info>,
+    institute: &Pubkey,
+    admitted_via: Option<Pubkey>,
+    now: i64,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let (expected_pda, bump) =
+        Pubkey::find_program_address(&[b"institute", institute.as_ref()], &crate::ID);
+    require!(
+        entry_info.key() == expected_pda && entry_info.is_writable,
+        ValidatorError::InstituteEntryMismatch
+    );
+
+    // Re-admitted institutes keep the signing key they last rotated to
+    let signing_key = if let Some(existing) = load_institute_entry(entry_info)? {
+        require!(
+            !existing.is_active(),
+            ValidatorError::InstituteAlreadyRegistered
+        );
+        existing.signing_key
+    } else {
+        create_pda_account(
+            entry_info,
+            InstituteEntry::LEN,
+            &crate::ID,
+            &[b"institute", institute.as_ref(), &[bump]],
+            payer,
+            system_program,
+        )?;
+        *institute
+    };
+
+    let entry = InstituteEntry {
+        institute: *institute,
+        signing_key,
+        status: InstituteStatus::Active,
+        joined_at: now,
+        removed_at: None,
+        admitted_via,
+        bump,
+    };
+    entry.try_serialize(&mut &mut entry_info.data.borrow_mut()[..])
+}
+
+/// Create an empty InstituteProfile PDA for `institute`
+/// Profiles of re-admitted institutes already exist and are left untouched
+fn create_institute_profile<'info>(
+    profile_info: &AccountInfo<'info>,
+    institute: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let (expected_pda, bump) =
+        Pubkey::find_program_address(&[b"institute_profile", institute.as_ref()], &crate::ID);
+    require!(
+        profile_info.key() == expected_pda && profile_info.is_writable,
+        ValidatorError::InstituteEntryMismatch
+    );
+
+    if !profile_info.data_is_empty() {
+        return Ok(());
+    }
+
+    create_pda_account(
+        profile_info,
+        InstituteProfile::LEN,
+        &crate::ID,
+        &[b"institute_profile", institute.as_ref(), &[bump]],
+        payer,
+        system_program,
+    )?;
+
+    let profile = InstituteProfile {
+        institute: *institute,
+        display_name: String::new(),
+        country_code: [0; 2],
+        accreditation_body: String::new(),
+        accreditation_id: String::new(),
+        uri: String::new(),
+        logo_hash: [0; 32],
+        updated_at: 0,
+        bump,
+    };
+    profile.try_serialize(&mut &mut profile_info.data.borrow_mut()[..])
+}
+
+/// Create the InstituteKey PDA mapping the identity key of `institute` to itself
+/// Fails if the key is already registered to a different institute
+fn create_institute_key<'info>(
+    key_info: &AccountInfo<'info>,
+    institute: &Pubkey,
+    now: i64,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let (expected_pda, bump) =
+        Pubkey::find_program_address(&[b"institute_key", institute.as_ref()], &crate::ID);
+    require!(
+        key_info.key() == expected_pda && key_info.is_writable,
+        ValidatorError::InstituteEntryMismatch
+    );
+
+    if let Some(existing) = load_institute_key(key_info)? {
+        require!(
+            existing.institute == *institute,
+            ValidatorError::KeyAlreadyInUse
+        );
+        return Ok(());
+    }
+
+    create_pda_account(
+        key_info,
+        InstituteKey::LEN,
+        &crate::ID,
+        &[b"institute_key", institute.as_ref(), &[bump]],
+        payer,
+        system_program,
+    )?;
+
+    let key = InstituteKey {
+        key: *institute,
+        institute: *institute,
+        permissions: InstituteKey::ALL_PERMISSIONS,
+        added_at: now,
+        retired_at: None,
+        bump,
+    };
+    key.try_serialize(&mut &mut key_info.data.borrow_mut()[..])
+}
+
+/// Allocate a PDA owned by `owner`, tolerating accounts that were pre-funded
+/// Shared with `certificate_system`, whose PDAs are created the same way
+pub fn create_pda_account<'info>(
+    target: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = target.lamports();
+
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            owner,
+        )
+    } else {
+        if current_lamports < rent {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: target.clone(),
+                    },
+                ),
+                rent - current_lamports,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Allocate {
+                    account_to_allocate: target.clone(),
+                },
+                &[signer_seeds],
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Assign {
+                    account_to_assign: target.clone(),
+                },
+                &[signer_seeds],
+            ),
+            owner,
+        )
+    }
+}
+
 // ============================================================================
 // Events
 // ============================================================================
@@ -371,16 +2216,46 @@ pub struct RegistryInitialized {
     pub initial_count: u32,
 }
 
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authorities: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub authorities: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardiansUpdated {
+    pub guardians: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub paused_scopes: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct ElectionCreated {
-    pub candidate: Pubkey,
+    pub kind: ElectionKind,
+    pub subject: Pubkey,
+    pub round: u32,
     pub eligible_voters: u32,
+    pub voting_ends_at: i64,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct VoteCast {
-    pub candidate: Pubkey,
+    pub subject: Pubkey,
     pub voter: Pubkey,
     pub vote_for: bool,
     pub timestamp: i64,
@@ -394,31 +2269,159 @@ pub struct InstituteAdmitted {
 }
 
 #[event]
-pub struct InstituteRejected {
-    pub candidate: Pubkey,
+pub struct ElectionRejected {
+    pub subject: Pubkey,
     pub votes_for: u32,
     pub votes_against: u32,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ElectionExpired {
+    pub subject: Pubkey,
+    pub votes_for: u32,
+    pub total_eligible_voters: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ElectionClosed {
+    pub subject: Pubkey,
+    pub round: u32,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceConfigUpdated {
+    pub approval_threshold_bps: u16,
+    pub quorum_bps: u16,
+    pub min_voting_period: i64,
+    pub removed_issuer_policy: RemovedIssuerPolicy,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InstituteKeyRotated {
+    pub institute: Pubkey,
+    pub old_key: Pubkey,
+    pub new_key: Pubkey,
+    pub old_key_retired_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InstituteDelegateAdded {
+    pub institute: Pubkey,
+    pub delegate: Pubkey,
+    pub permissions: u8,
+    pub expires_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InstituteDelegateRevoked {
+    pub institute: Pubkey,
+    pub delegate: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InstituteProfileUpdated {
+    pub institute: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct InstituteRemoved {
     pub institute: Pubkey,
     pub timestamp: i64,
 }
 
+// ============================================================================
+// Instruction Parameters
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct GovernanceParams {
+    /// Share of votes cast that must be in favour, in basis points (more than half)
+    pub approval_threshold_bps: u16,
+    /// Share of eligible voters that must cast a vote, in basis points
+    pub quorum_bps: u16,
+    /// Shortest voting period an election can be created with
+    pub min_voting_period: i64,
+    /// How verifiers treat certificates of removed institutes
+    pub removed_issuer_policy: RemovedIssuerPolicy,
+}
+
+impl GovernanceParams {
+    pub const LEN: usize = 2 + // approval_threshold_bps
+        2 + // quorum_bps
+        8 + // min_voting_period
+        1; // removed_issuer_policy (enum)
+
+    /// Check the parameters are within their allowed ranges
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.approval_threshold_bps > 5_000 && self.approval_threshold_bps <= 10_000,
+            ValidatorError::InvalidApprovalThreshold
+        );
+        require!(
+            self.quorum_bps > 0 && self.quorum_bps <= 10_000,
+            ValidatorError::InvalidQuorum
+        );
+        require!(
+            (VotingState::MIN_VOTING_PERIOD..=VotingState::MAX_VOTING_PERIOD)
+                .contains(&self.min_voting_period),
+            ValidatorError::InvalidVotingPeriod
+        );
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InstituteProfileParams {
+    pub display_name: String,
+    pub country_code: [u8; 2],
+    pub accreditation_body: String,
+    pub accreditation_id: String,
+    pub uri: String,
+    pub logo_hash: [u8; 32],
+}
+
+impl InstituteProfileParams {
+    /// Check field lengths against the space reserved in InstituteProfile
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.display_name.len() <= InstituteProfile::MAX_DISPLAY_NAME_LEN
+                && self.accreditation_body.len() <= InstituteProfile::MAX_ACCREDITATION_BODY_LEN
+                && self.accreditation_id.len() <= InstituteProfile::MAX_ACCREDITATION_ID_LEN
+                && self.uri.len() <= InstituteProfile::MAX_URI_LEN,
+            ValidatorError::ProfileFieldTooLong
+        );
+        require!(
+            self.country_code.iter().all(u8::is_ascii_uppercase),
+            ValidatorError::InvalidCountryCode
+        );
+        Ok(())
+    }
+}
+
 // ============================================================================
 // Return Types
 // ============================================================================
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct VotingStateView {
-    pub candidate_institute: Pubkey,
+    pub kind: ElectionKind,
+    pub subject: Pubkey,
+    pub round: u32,
     pub votes_for_count: u32,
     pub votes_against_count: u32,
     pub total_eligible_voters: u32,
     pub status: VotingStatus,
     pub created_at: i64,
+    pub voting_ends_at: i64,
     pub concluded_at: Option<i64>,
 }
 
@@ -445,4 +2448,91 @@ pub enum ValidatorError {
 
     #[msg("Institute not found in registry")]
     InstituteNotFound,
+
+    #[msg("Institute account does not match the expected PDA")]
+    InstituteEntryMismatch,
+
+    #[msg("Profile field exceeds its maximum length")]
+    ProfileFieldTooLong,
+
+    #[msg("Country code must be two uppercase ASCII letters")]
+    InvalidCountryCode,
+
+    #[msg("Signing key has been retired")]
+    KeyRetired,
+
+    #[msg("Signing key is already registered to another institute or candidate")]
+    KeyAlreadyInUse,
+
+    #[msg("Only the primary signing key can perform this action")]
+    NotPrimarySigningKey,
+
+    #[msg("Key retirement must be between now and the maximum grace period")]
+    InvalidKeyRetirement,
+
+    #[msg("Delegate permissions must be a non-empty subset of issue, correct and revoke")]
+    InvalidDelegatePermissions,
+
+    #[msg("Delegate expiry must be in the future")]
+    InvalidDelegateExpiry,
+
+    #[msg("Key is not a delegate")]
+    NotADelegate,
+
+    #[msg("Signing key lacks the permission for this action")]
+    MissingKeyPermission,
+
+    #[msg("Voting period must be between the minimum and maximum voting period")]
+    InvalidVotingPeriod,
+
+    #[msg("Voting period has ended")]
+    VotingPeriodEnded,
+
+    #[msg("Voting period has not ended yet")]
+    VotingPeriodNotEnded,
+
+    #[msg("Approval threshold must be above 5000 and at most 10000 basis points")]
+    InvalidApprovalThreshold,
+
+    #[msg("Quorum must be between 1 and 10000 basis points")]
+    InvalidQuorum,
+
+    #[msg("Accounts of the election subject are required to apply the outcome")]
+    MissingSubjectAccounts,
+
+    #[msg("An institute cannot vote on its own removal")]
+    CannotVoteOnOwnRemoval,
+
+    #[msg("The last registered institute cannot be removed")]
+    CannotRemoveLastInstitute,
+
+    #[msg("Not enough registry authorities signed")]
+    AuthorityThresholdNotMet,
+
+    #[msg("Authority set must hold 1 to MAX_AUTHORITIES unique keys and a reachable threshold")]
+    InvalidAuthoritySet,
+
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthorityTransfer,
+
+    #[msg("Guardian set must hold at most MAX_GUARDIANS unique keys")]
+    InvalidGuardianSet,
+
+    #[msg("Unknown pause scope")]
+    InvalidPauseScopes,
+
+    #[msg("This action is currently paused")]
+    ProgramPaused,
+
+    #[msg("An election of this kind is already open on the subject")]
+    ElectionAlreadyOpen,
+
+    #[msg("Election has not concluded yet")]
+    ElectionStillActive,
+
+    #[msg("Registry has reached InstituteRegistry::MAX_INSTITUTES members")]
+    RegistryFull,
+
+    #[msg("Voter joined the registry after the election opened")]
+    VoterNotInElectorate,
 }
@@ -4,8 +4,21 @@ import { Program } from "@coral-xyz/anchor";
 import { InstituteValidator } from "../target/types/institute_validator";
 import {
   airdrop,
+  DEFAULT_GOVERNANCE,
+  fetchRegisteredInstitutes,
+  findGovernanceConfigPDA,
+  findElectionHistoryPDA,
+  findGovernanceElectionPDA,
+  findInstituteEntryPDA,
+  findInstituteKeyPDA,
+  findInstituteProfilePDA,
   findInstituteRegistryPDA,
+  findRemovalElectionPDA,
   findVotingStatePDA,
+  initialInstituteAccounts,
+  PAUSE_GOVERNANCE,
+  PAUSE_ISSUANCE,
+  VOTING_PERIOD,
 } from "./utils/helpers";
 
 describe("Institute Validator", () => {
@@ -45,11 +58,9 @@ describe("Institute Validator", () => {
 
     // Check if registry already exists and get registered institutes
     try {
-      const existingRegistry = await program.account.instituteRegistry.fetch(
-        registryPDA
-      );
+      await program.account.instituteRegistry.fetch(registryPDA);
       isRegistryPreInitialized = true;
-      registeredInstitutes = existingRegistry.registeredInstitutes;
+      registeredInstitutes = await fetchRegisteredInstitutes(program);
       console.log(`Registry pre-initialized with ${registeredInstitutes.length} institutes`);
     } catch (error) {
       isRegistryPreInitialized = false;
@@ -76,12 +87,16 @@ describe("Institute Validator", () => {
         ];
 
         await program.methods
-          .initializeRegistry(initialInstitutes)
+          .initializeRegistry(initialInstitutes, DEFAULT_GOVERNANCE)
           .accounts({
             instituteRegistry: registryPDA,
+            governanceConfig: findGovernanceConfigPDA(program.programId)[0],
             authority: authority.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
+          .remainingAccounts(
+            initialInstituteAccounts(initialInstitutes, program.programId)
+          )
           .signers([authority])
           .rpc();
 
@@ -89,31 +104,36 @@ describe("Institute Validator", () => {
           registryPDA
         );
 
-        registeredInstitutes = registry.registeredInstitutes;
+        registeredInstitutes = await fetchRegisteredInstitutes(program);
 
-        expect(registry.registeredInstitutes).to.have.lengthOf(3);
+        expect(registry.instituteCount).to.equal(3);
         expect(
-          registry.registeredInstitutes.map((pk) => pk.toString())
+          registeredInstitutes.map((pk) => pk.toString())
         ).to.include.members([
           institute1.publicKey.toString(),
           institute2.publicKey.toString(),
           institute3.publicKey.toString(),
         ]);
-        expect(registry.authority.toString()).to.equal(
-          authority.publicKey.toString()
-        );
+        expect(registry.authorities.map((pk) => pk.toString())).to.deep.equal([
+          authority.publicKey.toString(),
+        ]);
+        expect(registry.authorityThreshold).to.equal(1);
       }
     });
 
     it("Should fail to initialize registry twice", async () => {
       try {
         await program.methods
-          .initializeRegistry([institute1.publicKey])
+          .initializeRegistry([institute1.publicKey], DEFAULT_GOVERNANCE)
           .accounts({
             instituteRegistry: registryPDA,
+            governanceConfig: findGovernanceConfigPDA(program.programId)[0],
             authority: authority.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
+          .remainingAccounts(
+            initialInstituteAccounts([institute1.publicKey], program.programId)
+          )
           .signers([authority])
           .rpc();
 
@@ -132,12 +152,27 @@ describe("Institute Validator", () => {
         candidateInstitute.publicKey,
         program.programId
       );
+      const registryBefore = await program.account.instituteRegistry.fetch(registryPDA);
 
       await program.methods
-        .newInstituteElection(candidateInstitute.publicKey)
+        .newInstituteElection(candidateInstitute.publicKey, VOTING_PERIOD)
         .accounts({
           votingState: votingStatePDA,
+          electionHistory: findElectionHistoryPDA(
+            "voting_state",
+            candidateInstitute.publicKey,
+            program.programId
+          )[0],
           instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+          candidateEntry: findInstituteEntryPDA(
+            candidateInstitute.publicKey,
+            program.programId
+          )[0],
+          candidateKey: findInstituteKeyPDA(
+            candidateInstitute.publicKey,
+            program.programId
+          )[0],
           proposer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -148,7 +183,7 @@ describe("Institute Validator", () => {
         votingStatePDA
       );
 
-      expect(votingState.candidateInstitute.toString()).to.equal(
+      expect(votingState.subject.toString()).to.equal(
         candidateInstitute.publicKey.toString()
       );
       expect(votingState.votesFor).to.have.lengthOf(0);
@@ -156,6 +191,10 @@ describe("Institute Validator", () => {
       // Total eligible voters should be >= 3 (might be more if registry was pre-initialized)
       expect(votingState.totalEligibleVoters).to.be.greaterThanOrEqual(3);
       expect(votingState.status).to.deep.equal({ active: {} });
+
+      // The candidate holds a seat in the registry until the election concludes
+      const registry = await program.account.instituteRegistry.fetch(registryPDA);
+      expect(registry.openAdmissions).to.equal(registryBefore.openAdmissions + 1);
     });
 
     it("Should fail to create election for already registered institute", async () => {
@@ -169,10 +208,24 @@ describe("Institute Validator", () => {
 
       try {
         await program.methods
-          .newInstituteElection(registeredInstitute)
+          .newInstituteElection(registeredInstitute, VOTING_PERIOD)
           .accounts({
             votingState: alreadyRegisteredVotingPDA,
+            electionHistory: findElectionHistoryPDA(
+              "voting_state",
+              registeredInstitute,
+              program.programId
+            )[0],
             instituteRegistry: registryPDA,
+            governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+            candidateEntry: findInstituteEntryPDA(
+              registeredInstitute,
+              program.programId
+            )[0],
+            candidateKey: findInstituteKeyPDA(
+              registeredInstitute,
+              program.programId
+            )[0],
             proposer: authority.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
@@ -184,6 +237,98 @@ describe("Institute Validator", () => {
         expect(error.toString()).to.include("InstituteAlreadyRegistered");
       }
     });
+
+    it("Should record the voting deadline", async () => {
+      const votingState = await program.account.votingState.fetch(
+        votingStatePDA
+      );
+
+      expect(
+        votingState.votingEndsAt.sub(votingState.createdAt).toNumber()
+      ).to.equal(VOTING_PERIOD.toNumber());
+    });
+
+    it("Should reject a voting period below the minimum", async () => {
+      const shortElectionCandidate = anchor.web3.Keypair.generate();
+
+      try {
+        await program.methods
+          .newInstituteElection(shortElectionCandidate.publicKey, new anchor.BN(60))
+          .accounts({
+            votingState: findVotingStatePDA(
+              shortElectionCandidate.publicKey,
+              program.programId
+            )[0],
+            electionHistory: findElectionHistoryPDA(
+              "voting_state",
+              shortElectionCandidate.publicKey,
+              program.programId
+            )[0],
+            instituteRegistry: registryPDA,
+            governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+            candidateEntry: findInstituteEntryPDA(
+              shortElectionCandidate.publicKey,
+              program.programId
+            )[0],
+            candidateKey: findInstituteKeyPDA(
+              shortElectionCandidate.publicKey,
+              program.programId
+            )[0],
+            proposer: authority.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidVotingPeriod");
+      }
+    });
+
+    it("Should not finalize an election before its deadline", async () => {
+      try {
+        await program.methods
+          .finalizeElection()
+          .accounts({
+            votingState: votingStatePDA,
+            electionHistory: findElectionHistoryPDA(
+              "voting_state",
+              candidateInstitute.publicKey,
+              program.programId
+            )[0],
+            instituteRegistry: registryPDA,
+            governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+            subjectEntry: null,
+            subjectProfile: null,
+            subjectKey: null,
+            payer: authority.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("VotingPeriodNotEnded");
+      }
+    });
+
+    it("Should not close an election that is still active", async () => {
+      try {
+        await program.methods
+          .closeElection()
+          .accounts({
+            votingState: votingStatePDA,
+            proposer: authority.publicKey,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("ElectionStillActive");
+      }
+    });
   });
 
   describe("Voting", () => {
@@ -219,10 +364,24 @@ describe("Institute Validator", () => {
       );
 
       await program.methods
-        .newInstituteElection(newCandidate.publicKey)
+        .newInstituteElection(newCandidate.publicKey, VOTING_PERIOD)
         .accounts({
           votingState: votingStatePDA,
+          electionHistory: findElectionHistoryPDA(
+            "voting_state",
+            newCandidate.publicKey,
+            program.programId
+          )[0],
           instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+          candidateEntry: findInstituteEntryPDA(
+            newCandidate.publicKey,
+            program.programId
+          )[0],
+          candidateKey: findInstituteKeyPDA(
+            newCandidate.publicKey,
+            program.programId
+          )[0],
           proposer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -235,12 +394,41 @@ describe("Institute Validator", () => {
         this.skip();
       }
 
+      const sizeBefore = (await provider.connection.getAccountInfo(votingStatePDA)).data.length;
+
       await program.methods
         .vote(true)
         .accounts({
           votingState: votingStatePDA,
+          electionHistory: findElectionHistoryPDA(
+            "voting_state",
+            newCandidate.publicKey,
+            program.programId
+          )[0],
           instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+          voterKey: findInstituteKeyPDA(
+            voter1Keypair.publicKey,
+            program.programId
+          )[0],
+          voterEntry: findInstituteEntryPDA(
+            voter1Keypair.publicKey,
+            program.programId
+          )[0],
+          subjectEntry: findInstituteEntryPDA(
+            newCandidate.publicKey,
+            program.programId
+          )[0],
+          subjectProfile: findInstituteProfilePDA(
+            newCandidate.publicKey,
+            program.programId
+          )[0],
+          subjectKey: findInstituteKeyPDA(
+            newCandidate.publicKey,
+            program.programId
+          )[0],
           voter: voter1Keypair.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([voter1Keypair])
         .rpc();
@@ -253,6 +441,10 @@ describe("Institute Validator", () => {
       expect(votingState.votesFor[0].toString()).to.equal(
         voter1Keypair.publicKey.toString()
       );
+
+      // The voter pays for growing the election by one key
+      const sizeAfter = (await provider.connection.getAccountInfo(votingStatePDA)).data.length;
+      expect(sizeAfter).to.equal(sizeBefore + 32);
     });
 
     it("Should allow registered institute to vote AGAINST", async function() {
@@ -264,8 +456,35 @@ describe("Institute Validator", () => {
         .vote(false)
         .accounts({
           votingState: votingStatePDA,
+          electionHistory: findElectionHistoryPDA(
+            "voting_state",
+            newCandidate.publicKey,
+            program.programId
+          )[0],
           instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+          voterKey: findInstituteKeyPDA(
+            voter2Keypair.publicKey,
+            program.programId
+          )[0],
+          voterEntry: findInstituteEntryPDA(
+            voter2Keypair.publicKey,
+            program.programId
+          )[0],
+          subjectEntry: findInstituteEntryPDA(
+            newCandidate.publicKey,
+            program.programId
+          )[0],
+          subjectProfile: findInstituteProfilePDA(
+            newCandidate.publicKey,
+            program.programId
+          )[0],
+          subjectKey: findInstituteKeyPDA(
+            newCandidate.publicKey,
+            program.programId
+          )[0],
           voter: voter2Keypair.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([voter2Keypair])
         .rpc();
@@ -290,8 +509,35 @@ describe("Institute Validator", () => {
           .vote(true)
           .accounts({
             votingState: votingStatePDA,
+            electionHistory: findElectionHistoryPDA(
+              "voting_state",
+              newCandidate.publicKey,
+              program.programId
+            )[0],
             instituteRegistry: registryPDA,
+            governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+            voterKey: findInstituteKeyPDA(
+              voter1Keypair.publicKey,
+              program.programId
+            )[0],
+            voterEntry: findInstituteEntryPDA(
+              voter1Keypair.publicKey,
+              program.programId
+            )[0],
+            subjectEntry: findInstituteEntryPDA(
+              newCandidate.publicKey,
+              program.programId
+            )[0],
+            subjectProfile: findInstituteProfilePDA(
+              newCandidate.publicKey,
+              program.programId
+            )[0],
+            subjectKey: findInstituteKeyPDA(
+              newCandidate.publicKey,
+              program.programId
+            )[0],
             voter: voter1Keypair.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([voter1Keypair])
           .rpc();
@@ -312,8 +558,35 @@ describe("Institute Validator", () => {
         .vote(true)
         .accounts({
           votingState: votingStatePDA,
+          electionHistory: findElectionHistoryPDA(
+            "voting_state",
+            newCandidate.publicKey,
+            program.programId
+          )[0],
           instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+          voterKey: findInstituteKeyPDA(
+            voter3Keypair.publicKey,
+            program.programId
+          )[0],
+          voterEntry: findInstituteEntryPDA(
+            voter3Keypair.publicKey,
+            program.programId
+          )[0],
+          subjectEntry: findInstituteEntryPDA(
+            newCandidate.publicKey,
+            program.programId
+          )[0],
+          subjectProfile: findInstituteProfilePDA(
+            newCandidate.publicKey,
+            program.programId
+          )[0],
+          subjectKey: findInstituteKeyPDA(
+            newCandidate.publicKey,
+            program.programId
+          )[0],
           voter: voter3Keypair.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([voter3Keypair])
         .rpc();
@@ -330,6 +603,7 @@ describe("Institute Validator", () => {
   describe("100% Approval Admission", () => {
     let votingStatePDA: anchor.web3.PublicKey;
     let approvedCandidate: anchor.web3.Keypair;
+    let earlierCandidate: anchor.web3.Keypair;
 
     before(async () => {
       // Skip if registry was pre-initialized (we don't have keypairs to vote)
@@ -344,11 +618,58 @@ describe("Institute Validator", () => {
         program.programId
       );
 
+      // Opened before the admission, so its electorate excludes the admitted candidate
+      earlierCandidate = anchor.web3.Keypair.generate();
       await program.methods
-        .newInstituteElection(approvedCandidate.publicKey)
+        .newInstituteElection(earlierCandidate.publicKey, VOTING_PERIOD)
+        .accounts({
+          votingState: findVotingStatePDA(
+            earlierCandidate.publicKey,
+            program.programId
+          )[0],
+          electionHistory: findElectionHistoryPDA(
+            "voting_state",
+            earlierCandidate.publicKey,
+            program.programId
+          )[0],
+          instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+          candidateEntry: findInstituteEntryPDA(
+            earlierCandidate.publicKey,
+            program.programId
+          )[0],
+          candidateKey: findInstituteKeyPDA(
+            earlierCandidate.publicKey,
+            program.programId
+          )[0],
+          proposer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      // Let the clock move past the second the earlier election opened in
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      await program.methods
+        .newInstituteElection(approvedCandidate.publicKey, VOTING_PERIOD)
         .accounts({
           votingState: votingStatePDA,
+          electionHistory: findElectionHistoryPDA(
+            "voting_state",
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
           instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+          candidateEntry: findInstituteEntryPDA(
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
+          candidateKey: findInstituteKeyPDA(
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
           proposer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -365,15 +686,42 @@ describe("Institute Validator", () => {
       const initialRegistry = await program.account.instituteRegistry.fetch(
         registryPDA
       );
-      const initialCount = initialRegistry.registeredInstitutes.length;
+      const initialCount = initialRegistry.instituteCount;
 
       // All three institutes vote FOR
       await program.methods
         .vote(true)
         .accounts({
           votingState: votingStatePDA,
+          electionHistory: findElectionHistoryPDA(
+            "voting_state",
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
           instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+          voterKey: findInstituteKeyPDA(
+            institute1.publicKey,
+            program.programId
+          )[0],
+          voterEntry: findInstituteEntryPDA(
+            institute1.publicKey,
+            program.programId
+          )[0],
+          subjectEntry: findInstituteEntryPDA(
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
+          subjectProfile: findInstituteProfilePDA(
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
+          subjectKey: findInstituteKeyPDA(
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
           voter: institute1.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([institute1])
         .rpc();
@@ -382,8 +730,35 @@ describe("Institute Validator", () => {
         .vote(true)
         .accounts({
           votingState: votingStatePDA,
+          electionHistory: findElectionHistoryPDA(
+            "voting_state",
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
           instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+          voterKey: findInstituteKeyPDA(
+            institute2.publicKey,
+            program.programId
+          )[0],
+          voterEntry: findInstituteEntryPDA(
+            institute2.publicKey,
+            program.programId
+          )[0],
+          subjectEntry: findInstituteEntryPDA(
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
+          subjectProfile: findInstituteProfilePDA(
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
+          subjectKey: findInstituteKeyPDA(
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
           voter: institute2.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([institute2])
         .rpc();
@@ -392,8 +767,35 @@ describe("Institute Validator", () => {
         .vote(true)
         .accounts({
           votingState: votingStatePDA,
+          electionHistory: findElectionHistoryPDA(
+            "voting_state",
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
           instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+          voterKey: findInstituteKeyPDA(
+            institute3.publicKey,
+            program.programId
+          )[0],
+          voterEntry: findInstituteEntryPDA(
+            institute3.publicKey,
+            program.programId
+          )[0],
+          subjectEntry: findInstituteEntryPDA(
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
+          subjectProfile: findInstituteProfilePDA(
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
+          subjectKey: findInstituteKeyPDA(
+            approvedCandidate.publicKey,
+            program.programId
+          )[0],
           voter: institute3.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([institute3])
         .rpc();
@@ -409,12 +811,567 @@ describe("Institute Validator", () => {
       expect(votingState.status).to.deep.equal({ approved: {} });
 
       // Check candidate was added to registry (should be one more than initial)
-      expect(registry.registeredInstitutes).to.have.lengthOf(initialCount + 1);
+      expect(registry.instituteCount).to.equal(initialCount + 1);
+      const candidateEntry = await program.account.instituteEntry.fetch(
+        findInstituteEntryPDA(approvedCandidate.publicKey, program.programId)[0]
+      );
+      expect(candidateEntry.status).to.deep.equal({ active: {} });
+      expect(candidateEntry.admittedVia.toString()).to.equal(
+        votingStatePDA.toString()
+      );
+
+      // An empty profile is created on admission
+      const candidateProfile = await program.account.instituteProfile.fetch(
+        findInstituteProfilePDA(approvedCandidate.publicKey, program.programId)[0]
+      );
+      expect(candidateProfile.institute.toString()).to.equal(
+        approvedCandidate.publicKey.toString()
+      );
+      expect(candidateProfile.displayName).to.equal("");
+    });
+
+    it("Should not let the admitted institute vote in an election opened before it joined", async function() {
+      if (isRegistryPreInitialized) {
+        this.skip();
+      }
+
+      try {
+        await program.methods
+          .vote(true)
+          .accounts({
+            votingState: findVotingStatePDA(
+              earlierCandidate.publicKey,
+              program.programId
+            )[0],
+            electionHistory: findElectionHistoryPDA(
+              "voting_state",
+              earlierCandidate.publicKey,
+              program.programId
+            )[0],
+            instituteRegistry: registryPDA,
+            governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+            voterKey: findInstituteKeyPDA(
+              approvedCandidate.publicKey,
+              program.programId
+            )[0],
+            voterEntry: findInstituteEntryPDA(
+              approvedCandidate.publicKey,
+              program.programId
+            )[0],
+            subjectEntry: findInstituteEntryPDA(
+              earlierCandidate.publicKey,
+              program.programId
+            )[0],
+            subjectProfile: findInstituteProfilePDA(
+              earlierCandidate.publicKey,
+              program.programId
+            )[0],
+            subjectKey: findInstituteKeyPDA(
+              earlierCandidate.publicKey,
+              program.programId
+            )[0],
+            voter: approvedCandidate.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([approvedCandidate])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("VoterNotInElectorate");
+      }
+    });
+
+    it("Should record the result in the election history", async function() {
+      if (isRegistryPreInitialized) {
+        this.skip();
+      }
+
+      const history = await program.account.electionHistory.fetch(
+        findElectionHistoryPDA(
+          "voting_state",
+          approvedCandidate.publicKey,
+          program.programId
+        )[0]
+      );
+
+      expect(history.rounds).to.equal(1);
+      expect(history.electionOpen).to.be.false;
+      expect(history.lastResult.round).to.equal(0);
+      expect(history.lastResult.status).to.deep.equal({ approved: {} });
+    });
+
+    it("Should close the concluded election and refund the proposer", async function() {
+      if (isRegistryPreInitialized) {
+        this.skip();
+      }
+
+      const rent = await provider.connection.getBalance(votingStatePDA);
+      const balanceBefore = await provider.connection.getBalance(
+        authority.publicKey
+      );
+
+      // Permissionless: sent by the provider wallet, the rent can only go to the proposer
+      await program.methods
+        .closeElection()
+        .accounts({
+          votingState: votingStatePDA,
+          proposer: authority.publicKey,
+        })
+        .rpc();
+
+      const balanceAfter = await provider.connection.getBalance(
+        authority.publicKey
+      );
+
+      expect(balanceAfter - balanceBefore).to.equal(rent);
       expect(
-        registry.registeredInstitutes.some(
-          (pk) => pk.toString() === approvedCandidate.publicKey.toString()
-        )
-      ).to.be.true;
+        await provider.connection.getAccountInfo(votingStatePDA)
+      ).to.be.null;
+    });
+  });
+
+  describe("Institute Profile", () => {
+    const profileParams = {
+      displayName: "University of Testing",
+      countryCode: Array.from(Buffer.from("IN")),
+      accreditationBody: "National Accreditation Board",
+      accreditationId: "NAB-0001",
+      uri: "https://testing.example.edu",
+      logoHash: Array.from(Buffer.alloc(32, 7)),
+    };
+
+    it("Should let an institute update its own profile", async function() {
+      if (isRegistryPreInitialized) {
+        this.skip();
+      }
+
+      const [profilePDA] = findInstituteProfilePDA(
+        institute1.publicKey,
+        program.programId
+      );
+
+      await program.methods
+        .updateInstituteProfile(profileParams)
+        .accounts({
+          instituteProfile: profilePDA,
+          instituteEntry: findInstituteEntryPDA(
+            institute1.publicKey,
+            program.programId
+          )[0],
+          instituteKey: findInstituteKeyPDA(
+            institute1.publicKey,
+            program.programId
+          )[0],
+          institute: institute1.publicKey,
+        })
+        .signers([institute1])
+        .rpc();
+
+      const profile = await program.account.instituteProfile.fetch(profilePDA);
+      expect(profile.displayName).to.equal(profileParams.displayName);
+      expect(Buffer.from(profile.countryCode).toString()).to.equal("IN");
+      expect(profile.uri).to.equal(profileParams.uri);
+      expect(profile.updatedAt.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("Should reject an invalid country code", async function() {
+      if (isRegistryPreInitialized) {
+        this.skip();
+      }
+
+      try {
+        await program.methods
+          .updateInstituteProfile({
+            ...profileParams,
+            countryCode: Array.from(Buffer.from("in")),
+          })
+          .accounts({
+            instituteProfile: findInstituteProfilePDA(
+              institute1.publicKey,
+              program.programId
+            )[0],
+            instituteEntry: findInstituteEntryPDA(
+              institute1.publicKey,
+              program.programId
+            )[0],
+            instituteKey: findInstituteKeyPDA(
+              institute1.publicKey,
+              program.programId
+            )[0],
+            institute: institute1.publicKey,
+          })
+          .signers([institute1])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidCountryCode");
+      }
+    });
+  });
+
+  describe("Governance", () => {
+    const twoThirdsMajority = {
+      approvalThresholdBps: 6_667,
+      quorumBps: 5_000,
+      minVotingPeriod: new anchor.BN(60 * 60),
+      removedIssuerPolicy: { flagAll: {} },
+    };
+
+    it("Should store the governance config set at initialization", async () => {
+      const config = await program.account.governanceConfig.fetch(
+        findGovernanceConfigPDA(program.programId)[0]
+      );
+
+      expect(config.approvalThresholdBps).to.be.greaterThan(5_000);
+      expect(config.quorumBps).to.be.greaterThan(0);
+    });
+
+    it("Should reject a simple-minority approval threshold", async () => {
+      try {
+        await program.methods
+          .newGovernanceElection(
+            { ...twoThirdsMajority, approvalThresholdBps: 5_000 },
+            VOTING_PERIOD
+          )
+          .accounts({
+            votingState: findGovernanceElectionPDA(program.programId)[0],
+            electionHistory: findElectionHistoryPDA(
+              "governance_election",
+              findGovernanceConfigPDA(program.programId)[0],
+              program.programId
+            )[0],
+            instituteRegistry: registryPDA,
+            governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+            proposer: authority.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidApprovalThreshold");
+      }
+    });
+
+    it("Should open a governance election for new parameters", async () => {
+      const [votingStatePDA] = findGovernanceElectionPDA(program.programId);
+
+      await program.methods
+        .newGovernanceElection(twoThirdsMajority, VOTING_PERIOD)
+        .accounts({
+          votingState: votingStatePDA,
+          electionHistory: findElectionHistoryPDA(
+            "governance_election",
+            findGovernanceConfigPDA(program.programId)[0],
+            program.programId
+          )[0],
+          instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+          proposer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      const votingState = await program.account.votingState.fetch(
+        votingStatePDA
+      );
+
+      expect(votingState.subject.toString()).to.equal(
+        findGovernanceConfigPDA(program.programId)[0].toString()
+      );
+      expect(
+        votingState.kind.governanceChange[0].approvalThresholdBps
+      ).to.equal(twoThirdsMajority.approvalThresholdBps);
+      expect(votingState.status).to.deep.equal({ active: {} });
+    });
+  });
+
+  describe("Institute Removal", () => {
+    let removalStatePDA: anchor.web3.PublicKey;
+
+    const removalVoteAccounts = (voter: anchor.web3.Keypair) => ({
+      votingState: removalStatePDA,
+      electionHistory: findElectionHistoryPDA(
+        "removal_election",
+        institute3.publicKey,
+        program.programId
+      )[0],
+      instituteRegistry: registryPDA,
+      governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+      voterKey: findInstituteKeyPDA(voter.publicKey, program.programId)[0],
+      voterEntry: findInstituteEntryPDA(voter.publicKey, program.programId)[0],
+      subjectEntry: findInstituteEntryPDA(
+        institute3.publicKey,
+        program.programId
+      )[0],
+      subjectProfile: null,
+      subjectKey: null,
+      voter: voter.publicKey,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    });
+
+    before(function() {
+      if (isRegistryPreInitialized) {
+        this.skip();
+      }
+
+      [removalStatePDA] = findRemovalElectionPDA(
+        institute3.publicKey,
+        program.programId
+      );
+    });
+
+    it("Should let the authority open a removal election", async () => {
+      await program.methods
+        .removeInstitute(institute3.publicKey, VOTING_PERIOD)
+        .accounts({
+          votingState: removalStatePDA,
+          electionHistory: findElectionHistoryPDA(
+            "removal_election",
+            institute3.publicKey,
+            program.programId
+          )[0],
+          instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+          instituteEntry: findInstituteEntryPDA(
+            institute3.publicKey,
+            program.programId
+          )[0],
+          authority: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      const registry = await program.account.instituteRegistry.fetch(
+        registryPDA
+      );
+      const votingState = await program.account.votingState.fetch(
+        removalStatePDA
+      );
+
+      expect(votingState.kind).to.deep.equal({ removal: {} });
+      expect(votingState.totalEligibleVoters).to.equal(
+        registry.instituteCount - 1
+      );
+    });
+
+    it("Should not let an institute vote on its own removal", async () => {
+      try {
+        await program.methods
+          .vote(false)
+          .accounts(removalVoteAccounts(institute3))
+          .signers([institute3])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("CannotVoteOnOwnRemoval");
+      }
+    });
+
+    it("Should keep the institute registered until the vote passes", async () => {
+      await program.methods
+        .vote(true)
+        .accounts(removalVoteAccounts(institute1))
+        .signers([institute1])
+        .rpc();
+
+      const votingState = await program.account.votingState.fetch(
+        removalStatePDA
+      );
+      const entry = await program.account.instituteEntry.fetch(
+        findInstituteEntryPDA(institute3.publicKey, program.programId)[0]
+      );
+
+      expect(votingState.status).to.deep.equal({ active: {} });
+      expect(entry.status).to.deep.equal({ active: {} });
+    });
+  });
+
+  describe("Emergency Pause", () => {
+    const guardian = anchor.web3.Keypair.generate();
+
+    before(function() {
+      if (isRegistryPreInitialized) {
+        this.skip();
+      }
+    });
+
+    it("Should let the authority appoint a guardian", async () => {
+      await program.methods
+        .setGuardians([guardian.publicKey])
+        .accounts({
+          instituteRegistry: registryPDA,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const registry = await program.account.instituteRegistry.fetch(registryPDA);
+      expect(registry.guardians.map((g) => g.toString())).to.deep.equal([
+        guardian.publicKey.toString(),
+      ]);
+      expect(registry.pausedScopes).to.equal(0);
+    });
+
+    it("Should block new elections while governance is paused", async () => {
+      await program.methods
+        .setPausedScopes(PAUSE_GOVERNANCE | PAUSE_ISSUANCE)
+        .accounts({
+          instituteRegistry: registryPDA,
+          authority: guardian.publicKey,
+        })
+        .signers([guardian])
+        .rpc();
+
+      const pausedCandidate = anchor.web3.Keypair.generate();
+
+      try {
+        await program.methods
+          .newInstituteElection(pausedCandidate.publicKey, VOTING_PERIOD)
+          .accounts({
+            votingState: findVotingStatePDA(
+              pausedCandidate.publicKey,
+              program.programId
+            )[0],
+            electionHistory: findElectionHistoryPDA(
+              "voting_state",
+              pausedCandidate.publicKey,
+              program.programId
+            )[0],
+            instituteRegistry: registryPDA,
+            governanceConfig: findGovernanceConfigPDA(program.programId)[0],
+            candidateEntry: findInstituteEntryPDA(
+              pausedCandidate.publicKey,
+              program.programId
+            )[0],
+            candidateKey: findInstituteKeyPDA(
+              pausedCandidate.publicKey,
+              program.programId
+            )[0],
+            proposer: authority.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("ProgramPaused");
+      }
+    });
+
+    it("Should not let a guardian lift a pause", async () => {
+      try {
+        await program.methods
+          .setPausedScopes(PAUSE_ISSUANCE)
+          .accounts({
+            instituteRegistry: registryPDA,
+            authority: guardian.publicKey,
+          })
+          .signers([guardian])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("AuthorityThresholdNotMet");
+      }
+    });
+
+    it("Should let the authority lift the pause", async () => {
+      await program.methods
+        .setPausedScopes(0)
+        .accounts({
+          instituteRegistry: registryPDA,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const registry = await program.account.instituteRegistry.fetch(registryPDA);
+      expect(registry.pausedScopes).to.equal(0);
+    });
+  });
+
+  describe("Registry Authority", () => {
+    const coAuthority = anchor.web3.Keypair.generate();
+
+    before(function() {
+      if (isRegistryPreInitialized) {
+        this.skip();
+      }
+    });
+
+    it("Should reject an unreachable authority threshold", async () => {
+      try {
+        await program.methods
+          .proposeAuthorityTransfer([authority.publicKey], 2)
+          .accounts({
+            instituteRegistry: registryPDA,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidAuthoritySet");
+      }
+    });
+
+    it("Should transfer authority to a 2-of-2 set in two steps", async () => {
+      await program.methods
+        .proposeAuthorityTransfer([authority.publicKey, coAuthority.publicKey], 2)
+        .accounts({
+          instituteRegistry: registryPDA,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      let registry = await program.account.instituteRegistry.fetch(registryPDA);
+      expect(registry.pendingTransfer).to.not.be.null;
+      expect(registry.authorityThreshold).to.equal(1);
+
+      // Both proposed authorities must sign to accept
+      await program.methods
+        .acceptAuthorityTransfer()
+        .accounts({
+          instituteRegistry: registryPDA,
+          authority: authority.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: coAuthority.publicKey, isSigner: true, isWritable: false },
+        ])
+        .signers([authority, coAuthority])
+        .rpc();
+
+      registry = await program.account.instituteRegistry.fetch(registryPDA);
+      expect(registry.pendingTransfer).to.be.null;
+      expect(registry.authorityThreshold).to.equal(2);
+      expect(registry.authorities).to.have.lengthOf(2);
+    });
+
+    it("Should require the threshold of authorities for admin actions", async () => {
+      try {
+        await program.methods
+          .proposeAuthorityTransfer([authority.publicKey], 1)
+          .accounts({
+            instituteRegistry: registryPDA,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("AuthorityThresholdNotMet");
+      }
     });
   });
-});
\ No newline at end of file
+});
@@ -3,7 +3,30 @@ import { Program } from "@coral-xyz/anchor";
 import { CertificateSystem } from "../target/types/certificate_system";
 import { InstituteValidator } from "../target/types/institute_validator";
 import { airdrop, findInstituteRegistryPDA, findVotingStatePDA } from "./utils/helpers";
-import { generateCertificateHash, findCertificatePDA } from "./utils/helpers";
+import {
+  DEFAULT_GOVERNANCE,
+  fetchRegisteredInstitutes,
+  findElectionHistoryPDA,
+  findGovernanceConfigPDA,
+  findInstituteEntryPDA,
+  findInstituteKeyPDA,
+  findInstituteProfilePDA,
+  initialInstituteAccounts,
+  VOTING_PERIOD,
+} from "./utils/helpers";
+import {
+  generateCertificateHash,
+  findCertificatePDA,
+  issuanceAccounts,
+  createCertificateFilterAccount,
+  findCertificateFilterStatePDA,
+} from "./utils/helpers";
+import {
+  buildMerkleTree,
+  findBatchRevocationPDA,
+  findCertificateBatchPDA,
+  findIssuerStatsPDA,
+} from "./utils/helpers";
 import { expect } from "chai";
 
 describe("Integration Tests: Full Workflow", () => {
@@ -34,26 +57,57 @@ describe("Integration Tests: Full Workflow", () => {
 
     // Check if registry already exists
     try {
-      const existingRegistry = await validatorProgram.account.instituteRegistry.fetch(
-        registryPDA
-      );
+      await validatorProgram.account.instituteRegistry.fetch(registryPDA);
       console.log("Registry already exists, using existing registry");
-      existingInstitutes = existingRegistry.registeredInstitutes;
+      existingInstitutes = await fetchRegisteredInstitutes(validatorProgram);
     } catch (error) {
       // Registry doesn't exist, initialize with one founding institute
       console.log("Initializing new registry");
       await validatorProgram.methods
-        .initializeRegistry([foundingInstitute.publicKey])
+        .initializeRegistry([foundingInstitute.publicKey], DEFAULT_GOVERNANCE)
         .accounts({
           instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPDA(validatorProgram.programId)[0],
           authority: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
+        .remainingAccounts(
+          initialInstituteAccounts([foundingInstitute.publicKey], validatorProgram.programId)
+        )
         .signers([authority])
         .rpc();
 
       existingInstitutes = [foundingInstitute.publicKey];
     }
+
+    // Issuance needs the certificate filter, initialized by the registry authority; its
+    // rent is paid by the provider wallet
+    const [filterStatePDA] = findCertificateFilterStatePDA(certificateProgram.programId);
+    const filterState =
+      await certificateProgram.account.certificateFilterState.fetchNullable(filterStatePDA);
+    if (!filterState) {
+      const filter = anchor.web3.Keypair.generate();
+      await certificateProgram.methods
+        .initializeCertificateFilter()
+        .accounts({
+          filterState: filterStatePDA,
+          filter: filter.publicKey,
+          instituteValidatorProgram: validatorProgram.programId,
+          instituteRegistry: registryPDA,
+          authority: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .preInstructions([
+          await createCertificateFilterAccount(
+            provider.connection,
+            provider.wallet.publicKey,
+            filter.publicKey,
+            certificateProgram.programId
+          ),
+        ])
+        .signers([filter, authority])
+        .rpc();
+    }
   });
 
   it("Complete workflow: Admit new institute and issue certificate", async function() {
@@ -75,10 +129,24 @@ describe("Integration Tests: Full Workflow", () => {
     );
 
     await validatorProgram.methods
-      .newInstituteElection(newInstitute.publicKey)
+      .newInstituteElection(newInstitute.publicKey, VOTING_PERIOD)
       .accounts({
         votingState: votingStatePDA,
+        electionHistory: findElectionHistoryPDA(
+          "voting_state",
+          newInstitute.publicKey,
+          validatorProgram.programId
+        )[0],
         instituteRegistry: registryPDA,
+        governanceConfig: findGovernanceConfigPDA(validatorProgram.programId)[0],
+        candidateEntry: findInstituteEntryPDA(
+          newInstitute.publicKey,
+          validatorProgram.programId
+        )[0],
+        candidateKey: findInstituteKeyPDA(
+          newInstitute.publicKey,
+          validatorProgram.programId
+        )[0],
         proposer: authority.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -90,8 +158,35 @@ describe("Integration Tests: Full Workflow", () => {
       .vote(true)
       .accounts({
         votingState: votingStatePDA,
+        electionHistory: findElectionHistoryPDA(
+          "voting_state",
+          newInstitute.publicKey,
+          validatorProgram.programId
+        )[0],
         instituteRegistry: registryPDA,
+        governanceConfig: findGovernanceConfigPDA(validatorProgram.programId)[0],
+        voterKey: findInstituteKeyPDA(
+          foundingInstitute.publicKey,
+          validatorProgram.programId
+        )[0],
+        voterEntry: findInstituteEntryPDA(
+          foundingInstitute.publicKey,
+          validatorProgram.programId
+        )[0],
+        subjectEntry: findInstituteEntryPDA(
+          newInstitute.publicKey,
+          validatorProgram.programId
+        )[0],
+        subjectProfile: findInstituteProfilePDA(
+          newInstitute.publicKey,
+          validatorProgram.programId
+        )[0],
+        subjectKey: findInstituteKeyPDA(
+          newInstitute.publicKey,
+          validatorProgram.programId
+        )[0],
         voter: foundingInstitute.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
       })
       .signers([foundingInstitute])
       .rpc();
@@ -100,12 +195,11 @@ describe("Integration Tests: Full Workflow", () => {
     const registry = await validatorProgram.account.instituteRegistry.fetch(
       registryPDA
     );
-    expect(registry.registeredInstitutes).to.have.lengthOf(initialInstituteCount + 1);
-    expect(
-      registry.registeredInstitutes.some(
-        (pk) => pk.toString() === newInstitute.publicKey.toString()
-      )
-    ).to.be.true;
+    expect(registry.instituteCount).to.equal(initialInstituteCount + 1);
+    const newEntry = await validatorProgram.account.instituteEntry.fetch(
+      findInstituteEntryPDA(newInstitute.publicKey, validatorProgram.programId)[0]
+    );
+    expect(newEntry.status).to.deep.equal({ active: {} });
 
     // Step 4: New institute issues a certificate
     const certHash = generateCertificateHash();
@@ -115,12 +209,15 @@ describe("Integration Tests: Full Workflow", () => {
     );
 
     await certificateProgram.methods
-      .addCertificate(Array.from(certHash))
+      .addCertificate(Array.from(certHash), null, null)
       .accounts({
         certificate: certificatePDA,
         issuer: newInstitute.publicKey,
-        instituteRegistry: registryPDA,
+        issuerKey: findInstituteKeyPDA(newInstitute.publicKey, validatorProgram.programId)[0],
+        issuerEntry: findInstituteEntryPDA(newInstitute.publicKey, validatorProgram.programId)[0],
+        ...(await issuanceAccounts(certificateProgram, newInstitute.publicKey)),
         instituteValidatorProgram: validatorProgram.programId,
+        instituteRegistry: registryPDA,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .signers([newInstitute])
@@ -147,11 +244,11 @@ describe("Integration Tests: Full Workflow", () => {
     if (existingInstitutes.length > 1 || 
         !existingInstitutes.some(pk => pk.equals(foundingInstitute.publicKey))) {
       // Use newInstitute if it was admitted in previous run, otherwise skip
-      const registry = await validatorProgram.account.instituteRegistry.fetch(registryPDA);
+      const registeredInstitutes = await fetchRegisteredInstitutes(validatorProgram);
       
-      if (registry.registeredInstitutes.some(pk => pk.equals(newInstitute.publicKey))) {
+      if (registeredInstitutes.some(pk => pk.equals(newInstitute.publicKey))) {
         issuerKeypair = newInstitute;
-      } else if (registry.registeredInstitutes.some(pk => pk.equals(foundingInstitute.publicKey))) {
+      } else if (registeredInstitutes.some(pk => pk.equals(foundingInstitute.publicKey))) {
         issuerKeypair = foundingInstitute;
       } else {
         console.log("No usable keypairs available, skipping certificate verification test");
@@ -159,8 +256,8 @@ describe("Integration Tests: Full Workflow", () => {
       }
     } else {
       // Use founding institute or newly admitted institute
-      const registry = await validatorProgram.account.instituteRegistry.fetch(registryPDA);
-      issuerKeypair = registry.registeredInstitutes.some(pk => pk.equals(newInstitute.publicKey))
+      const registeredInstitutes = await fetchRegisteredInstitutes(validatorProgram);
+      issuerKeypair = registeredInstitutes.some(pk => pk.equals(newInstitute.publicKey))
         ? newInstitute
         : foundingInstitute;
     }
@@ -173,26 +270,40 @@ describe("Integration Tests: Full Workflow", () => {
     );
 
     await certificateProgram.methods
-      .addCertificate(Array.from(certHash))
+      .addCertificate(Array.from(certHash), null, null)
       .accounts({
         certificate: certificatePDA,
         issuer: issuerKeypair.publicKey,
-        instituteRegistry: registryPDA,
+        issuerKey: findInstituteKeyPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+        issuerEntry: findInstituteEntryPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+        ...(await issuanceAccounts(certificateProgram, issuerKeypair.publicKey)),
         instituteValidatorProgram: validatorProgram.programId,
+        instituteRegistry: registryPDA,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .signers([issuerKeypair])
       .rpc();
 
-    // Verify the certificate
+    // Verify the certificate, resolving the issuer profile
     const status = await certificateProgram.methods
       .verifyCertificate()
       .accounts({
         certificate: certificatePDA,
+        instituteValidatorProgram: validatorProgram.programId,
+        instituteRegistry: registryPDA,
+        issuerEntry: findInstituteEntryPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+        governanceConfig: findGovernanceConfigPDA(validatorProgram.programId)[0],
+        issuerProfile: findInstituteProfilePDA(
+          issuerKeypair.publicKey,
+          validatorProgram.programId
+        )[0],
+        credentialSchema: null,
       })
       .view();
 
     expect(status.isValid).to.be.true;
+    expect(status.issuerStatus).to.deep.equal({ active: {} });
+    expect(status.issuerProfile).to.not.be.null;
     expect(status.issuer.toString()).to.equal(issuerKeypair.publicKey.toString());
     expect(Array.from(status.certificateHash)).to.deep.equal(Array.from(certHash));
     console.log("✅ Certificate verification successful!");
@@ -210,12 +321,15 @@ describe("Integration Tests: Full Workflow", () => {
 
     try {
       await certificateProgram.methods
-        .addCertificate(Array.from(certHash))
+        .addCertificate(Array.from(certHash), null, null)
         .accounts({
           certificate: certificatePDA,
           issuer: unregisteredInstitute.publicKey,
-          instituteRegistry: registryPDA,
+          issuerKey: findInstituteKeyPDA(unregisteredInstitute.publicKey, validatorProgram.programId)[0],
+          issuerEntry: findInstituteEntryPDA(unregisteredInstitute.publicKey, validatorProgram.programId)[0],
+          ...(await issuanceAccounts(certificateProgram, unregisteredInstitute.publicKey)),
           instituteValidatorProgram: validatorProgram.programId,
+          instituteRegistry: registryPDA,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([unregisteredInstitute])
@@ -232,10 +346,10 @@ describe("Integration Tests: Full Workflow", () => {
     let issuerKeypair: anchor.web3.Keypair;
     
     // Find a usable keypair
-    const registry = await validatorProgram.account.instituteRegistry.fetch(registryPDA);
-    if (registry.registeredInstitutes.some(pk => pk.equals(newInstitute.publicKey))) {
+    const registeredInstitutes = await fetchRegisteredInstitutes(validatorProgram);
+    if (registeredInstitutes.some(pk => pk.equals(newInstitute.publicKey))) {
       issuerKeypair = newInstitute;
-    } else if (registry.registeredInstitutes.some(pk => pk.equals(foundingInstitute.publicKey))) {
+    } else if (registeredInstitutes.some(pk => pk.equals(foundingInstitute.publicKey))) {
       issuerKeypair = foundingInstitute;
     } else {
       console.log("No usable keypairs available, skipping correction test");
@@ -250,12 +364,15 @@ describe("Integration Tests: Full Workflow", () => {
     );
 
     await certificateProgram.methods
-      .addCertificate(Array.from(oldCertHash))
+      .addCertificate(Array.from(oldCertHash), null, null)
       .accounts({
         certificate: oldCertPDA,
         issuer: issuerKeypair.publicKey,
-        instituteRegistry: registryPDA,
+        issuerKey: findInstituteKeyPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+        issuerEntry: findInstituteEntryPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+        ...(await issuanceAccounts(certificateProgram, issuerKeypair.publicKey)),
         instituteValidatorProgram: validatorProgram.programId,
+        instituteRegistry: registryPDA,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .signers([issuerKeypair])
@@ -274,8 +391,11 @@ describe("Integration Tests: Full Workflow", () => {
         oldCertificatePda: oldCertPDA,
         newCertificate: newCertPDA,
         issuer: issuerKeypair.publicKey,
-        instituteRegistry: registryPDA,
+        issuerKey: findInstituteKeyPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+        issuerEntry: findInstituteEntryPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+        ...(await issuanceAccounts(certificateProgram, issuerKeypair.publicKey)),
         instituteValidatorProgram: validatorProgram.programId,
+        instituteRegistry: registryPDA,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .signers([issuerKeypair])
@@ -291,4 +411,146 @@ describe("Integration Tests: Full Workflow", () => {
     expect(newCert.isValid).to.be.true;
     console.log("✅ Certificate correction successful!");
   });
-});
\ No newline at end of file
+
+  it("Should issue a batch and verify individual certificates by Merkle proof", async () => {
+    // Find a usable keypair
+    const registeredInstitutes = await fetchRegisteredInstitutes(validatorProgram);
+    let issuerKeypair: anchor.web3.Keypair;
+    if (registeredInstitutes.some(pk => pk.equals(newInstitute.publicKey))) {
+      issuerKeypair = newInstitute;
+    } else if (registeredInstitutes.some(pk => pk.equals(foundingInstitute.publicKey))) {
+      issuerKeypair = foundingInstitute;
+    } else {
+      console.log("No usable keypairs available, skipping batch test");
+      return;
+    }
+
+    const certHashes = Array.from({ length: 5 }, () => generateCertificateHash());
+    const tree = buildMerkleTree(certHashes);
+    const [batchPDA] = findCertificateBatchPDA(
+      issuerKeypair.publicKey,
+      tree.root,
+      certificateProgram.programId
+    );
+
+    await certificateProgram.methods
+      .addCertificateBatch(Array.from(tree.root), certHashes.length, null)
+      .accounts({
+        batch: batchPDA,
+        issuer: issuerKeypair.publicKey,
+        issuerKey: findInstituteKeyPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+        issuerEntry: findInstituteEntryPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+        instituteValidatorProgram: validatorProgram.programId,
+        instituteRegistry: registryPDA,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+
+    // Verify a leaf with its proof
+    const status = await certificateProgram.methods
+      .verifyInBatch(
+        Array.from(certHashes[3]),
+        3,
+        tree.proof(3).map((node) => Array.from(node))
+      )
+      .accounts({
+        batch: batchPDA,
+        revocation: null,
+        instituteValidatorProgram: validatorProgram.programId,
+        instituteRegistry: registryPDA,
+        issuerEntry: findInstituteEntryPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+        governanceConfig: findGovernanceConfigPDA(validatorProgram.programId)[0],
+        issuerProfile: null,
+      })
+      .view();
+
+    expect(status.isValid).to.be.true;
+    expect(status.issuer.toString()).to.equal(issuerKeypair.publicKey.toString());
+
+    // A proof for a different position must fail
+    try {
+      await certificateProgram.methods
+        .verifyInBatch(
+          Array.from(certHashes[3]),
+          2,
+          tree.proof(3).map((node) => Array.from(node))
+        )
+        .accounts({
+          batch: batchPDA,
+          revocation: null,
+          instituteValidatorProgram: validatorProgram.programId,
+          instituteRegistry: registryPDA,
+          issuerEntry: findInstituteEntryPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+          governanceConfig: findGovernanceConfigPDA(validatorProgram.programId)[0],
+          issuerProfile: null,
+        })
+        .view();
+
+      expect.fail("Should have thrown InvalidMerkleProof error");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidMerkleProof");
+    }
+
+    // Revoke the leaf and verify again
+    const [revocationPDA] = findBatchRevocationPDA(
+      batchPDA,
+      3,
+      certificateProgram.programId
+    );
+    const [issuerStatsPDA] = findIssuerStatsPDA(
+      issuerKeypair.publicKey,
+      certificateProgram.programId
+    );
+    const statsBefore = await certificateProgram.account.issuerStats.fetchNullable(issuerStatsPDA);
+
+    await certificateProgram.methods
+      .revokeBatchLeaf(
+        Array.from(certHashes[3]),
+        3,
+        tree.proof(3).map((node) => Array.from(node)),
+        { clericalError: {} },
+        null
+      )
+      .accounts({
+        batch: batchPDA,
+        revocation: revocationPDA,
+        issuer: issuerKeypair.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        instituteValidatorProgram: validatorProgram.programId,
+        instituteRegistry: registryPDA,
+        issuerKey: findInstituteKeyPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+        issuerStats: issuerStatsPDA,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+
+    // Batch leaf revocations count in the institute's IssuerStats
+    const stats = await certificateProgram.account.issuerStats.fetch(issuerStatsPDA);
+    expect(stats.revokedCount.toNumber()).to.equal(
+      (statsBefore ? statsBefore.revokedCount.toNumber() : 0) + 1
+    );
+
+    const revokedStatus = await certificateProgram.methods
+      .verifyInBatch(
+        Array.from(certHashes[3]),
+        3,
+        tree.proof(3).map((node) => Array.from(node))
+      )
+      .accounts({
+        batch: batchPDA,
+        revocation: revocationPDA,
+        instituteValidatorProgram: validatorProgram.programId,
+        instituteRegistry: registryPDA,
+        issuerEntry: findInstituteEntryPDA(issuerKeypair.publicKey, validatorProgram.programId)[0],
+        governanceConfig: findGovernanceConfigPDA(validatorProgram.programId)[0],
+        issuerProfile: null,
+      })
+      .view();
+
+    expect(revokedStatus.isValid).to.be.false;
+    expect(revokedStatus.state).to.deep.equal({ revoked: {} });
+    expect(revokedStatus.revocationReason).to.deep.equal({ clericalError: {} });
+    console.log("✅ Batch issuance and revocation successful!");
+  });
+});
@@ -1,8 +1,25 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { Keypair, PublicKey } from "@solana/web3.js";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
 import * as crypto from "crypto";
 
+// Voting period used for elections in tests (1 day)
+export const VOTING_PERIOD = new anchor.BN(24 * 60 * 60);
+
+// Unanimity of all eligible voters, as the registry originally required
+export const DEFAULT_GOVERNANCE = {
+  approvalThresholdBps: 10_000,
+  quorumBps: 10_000,
+  minVotingPeriod: new anchor.BN(60 * 60),
+  removedIssuerPolicy: { grandfather: {} },
+};
+
+// Pause scopes of the InstituteRegistry (InstituteRegistry::PAUSE_*)
+export const PAUSE_ISSUANCE = 1 << 0;
+export const PAUSE_REVOCATION = 1 << 1;
+export const PAUSE_GOVERNANCE = 1 << 2;
+export const PAUSE_VERIFICATION = 1 << 3;
+
 export function generateCertificateHash(): Buffer {
   return crypto.randomBytes(32);
 }
@@ -35,12 +52,294 @@ export function findInstituteRegistryPDA(
   );
 }
 
+export function findGovernanceConfigPDA(
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config")],
+    programId
+  );
+}
+
+// Elections on a subject are numbered in rounds, starting at 0
+function roundSeed(round: number): Buffer {
+  const roundBytes = Buffer.alloc(4);
+  roundBytes.writeUInt32LE(round);
+  return roundBytes;
+}
+
+export function findGovernanceElectionPDA(
+  programId: PublicKey,
+  round: number = 0
+): [PublicKey, number] {
+  const [governanceConfig] = findGovernanceConfigPDA(programId);
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("governance_election"),
+      governanceConfig.toBuffer(),
+      roundSeed(round),
+    ],
+    programId
+  );
+}
+
+export function findRemovalElectionPDA(
+  institute: PublicKey,
+  programId: PublicKey,
+  round: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("removal_election"), institute.toBuffer(), roundSeed(round)],
+    programId
+  );
+}
+
 export function findVotingStatePDA(
   candidateInstitute: PublicKey,
+  programId: PublicKey,
+  round: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("voting_state"),
+      candidateInstitute.toBuffer(),
+      roundSeed(round),
+    ],
+    programId
+  );
+}
+
+// `seedPrefix` is the first seed of the election's VotingState PDA:
+// "voting_state", "removal_election" or "governance_election"
+export function findElectionHistoryPDA(
+  seedPrefix: string,
+  subject: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("election_history"), Buffer.from(seedPrefix), subject.toBuffer()],
+    programId
+  );
+}
+
+export function findIssuerStatsPDA(
+  institute: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("issuer_stats"), institute.toBuffer()],
+    programId
+  );
+}
+
+export function findCertificateIndexPDA(
+  institute: PublicKey,
+  serial: number,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("cert_by_issuer"),
+      institute.toBuffer(),
+      new anchor.BN(serial).toArrayLike(Buffer, "le", 8),
+    ],
+    programId
+  );
+}
+
+// IssuerStats, next CertificateIndex and current CertificateFilter accounts expected
+// by add_certificate and correct_certificate; the stats account only exists after
+// the first certificate
+export async function issuanceAccounts(
+  program: Program<any>,
+  institute: PublicKey
+): Promise<{
+  issuerStats: PublicKey;
+  certificateIndex: PublicKey;
+  filterState: PublicKey;
+  certificateFilter: PublicKey | null;
+}> {
+  const [issuerStats] = findIssuerStatsPDA(institute, program.programId);
+  const stats = await program.account.issuerStats.fetchNullable(issuerStats);
+  const nextSerial = stats ? stats.nextSerial.toNumber() : 0;
+  // The filter is optional until it is initialized
+  const [filterState] = findCertificateFilterStatePDA(program.programId);
+  const state = await program.account.certificateFilterState.fetchNullable(filterState);
+  return {
+    issuerStats,
+    certificateIndex: findCertificateIndexPDA(institute, nextSerial, program.programId)[0],
+    filterState,
+    certificateFilter: state ? state.current : null,
+  };
+}
+
+// Size of a CertificateFilter account (CertificateFilter::LEN): discriminator,
+// 112-byte header and 1 MiB of filter bits
+export const CERTIFICATE_FILTER_LEN = 8 + 112 + 1_048_576;
+
+export function findCertificateFilterStatePDA(
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("certificate_filter_state")],
+    programId
+  );
+}
+
+// CertificateFilter generations are too large to be created via CPI: the account is
+// created by the system program in the same transaction as the filter instruction
+export async function createCertificateFilterAccount(
+  connection: anchor.web3.Connection,
+  payer: PublicKey,
+  filter: PublicKey,
+  programId: PublicKey
+): Promise<anchor.web3.TransactionInstruction> {
+  return SystemProgram.createAccount({
+    fromPubkey: payer,
+    newAccountPubkey: filter,
+    lamports: await connection.getMinimumBalanceForRentExemption(CERTIFICATE_FILTER_LEN),
+    space: CERTIFICATE_FILTER_LEN,
+    programId,
+  });
+}
+
+// Mirrors CertificateFilter::contains over the raw account data: bit positions by
+// double hashing the first two little-endian words of the certificate hash
+export function certificateFilterContains(data: Buffer, certificateHash: number[]): boolean {
+  const hash = Buffer.from(certificateHash);
+  const numBits = new anchor.BN(data.subarray(16, 24), "le");
+  const numHashes = data.readUInt32LE(24);
+  const h1 = new anchor.BN(hash.subarray(0, 8), "le");
+  const h2 = new anchor.BN(hash.subarray(8, 16), "le").setn(0, true);
+  for (let i = 0; i < numHashes; i++) {
+    const position = h1.add(h2.muln(i)).maskn(64).umod(numBits).toNumber();
+    if ((data[120 + Math.floor(position / 8)] & (1 << position % 8)) === 0) {
+      return false;
+    }
+  }
+  return true;
+}
+
+export function findCertificateBatchPDA(
+  institute: PublicKey,
+  merkleRoot: Buffer,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("voting_state"), candidateInstitute.toBuffer()],
+    [Buffer.from("certificate_batch"), institute.toBuffer(), merkleRoot],
     programId
   );
-}
\ No newline at end of file
+}
+
+export function findBatchRevocationPDA(
+  batch: PublicKey,
+  leafIndex: number,
+  programId: PublicKey
+): [PublicKey, number] {
+  const leafIndexBytes = Buffer.alloc(4);
+  leafIndexBytes.writeUInt32LE(leafIndex);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("batch_revocation"), batch.toBuffer(), leafIndexBytes],
+    programId
+  );
+}
+
+// Mirrors the on-chain Merkle layout: domain-separated leaves and nodes,
+// leaf level padded with zeroed nodes up to the next power of two
+export function buildMerkleTree(certificateHashes: Buffer[]): {
+  root: Buffer;
+  proof: (leafIndex: number) => Buffer[];
+} {
+  const sha256 = (...parts: Buffer[]) =>
+    crypto.createHash("sha256").update(Buffer.concat(parts)).digest();
+
+  let width = 1;
+  while (width < certificateHashes.length) width *= 2;
+
+  const levels: Buffer[][] = [
+    Array.from({ length: width }, (_, i) =>
+      i < certificateHashes.length
+        ? sha256(Buffer.from([0x00]), certificateHashes[i])
+        : Buffer.alloc(32)
+    ),
+  ];
+  while (levels[levels.length - 1].length > 1) {
+    const level = levels[levels.length - 1];
+    const next: Buffer[] = [];
+    for (let i = 0; i < level.length; i += 2) {
+      next.push(sha256(Buffer.from([0x01]), level[i], level[i + 1]));
+    }
+    levels.push(next);
+  }
+
+  return {
+    root: levels[levels.length - 1][0],
+    proof: (leafIndex: number) =>
+      levels.slice(0, -1).map((level, depth) => level[(leafIndex >> depth) ^ 1]),
+  };
+}
+
+export function findInstituteEntryPDA(
+  institute: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("institute"), institute.toBuffer()],
+    programId
+  );
+}
+
+export function findInstituteProfilePDA(
+  institute: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("institute_profile"), institute.toBuffer()],
+    programId
+  );
+}
+
+export function findInstituteKeyPDA(
+  key: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("institute_key"), key.toBuffer()],
+    programId
+  );
+}
+
+// Remaining accounts expected by initialize_registry: the entry, profile and
+// key PDAs of each institute, in order
+export function initialInstituteAccounts(
+  institutes: PublicKey[],
+  programId: PublicKey
+): anchor.web3.AccountMeta[] {
+  return institutes.flatMap((institute) => [
+    {
+      pubkey: findInstituteEntryPDA(institute, programId)[0],
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: findInstituteProfilePDA(institute, programId)[0],
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: findInstituteKeyPDA(institute, programId)[0],
+      isWritable: true,
+      isSigner: false,
+    },
+  ]);
+}
+
+// Active institutes, discovered by scanning InstituteEntry accounts
+export async function fetchRegisteredInstitutes(
+  program: Program<any>
+): Promise<PublicKey[]> {
+  const entries = await program.account.instituteEntry.all();
+  return entries
+    .filter((entry) => "active" in entry.account.status)
+    .map((entry) => entry.account.institute);
+}
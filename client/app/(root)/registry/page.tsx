@@ -4,6 +4,7 @@ import { useWallet } from "@solana/wallet-adapter-react";
 import { useState } from "react";
 import { Button } from "@/components/ui/button";
 import { PublicKey } from "@solana/web3.js";
+import { DEFAULT_GOVERNANCE, findGovernanceConfigPda } from "@/lib/accounts";
 
 export default function RegistryPage() {
   const { publicKey } = useWallet();
@@ -26,9 +27,10 @@ export default function RegistryPage() {
       );
 
       const tx = await validatorProgram.methods
-        .initializeRegistry([])
+        .initializeRegistry([], DEFAULT_GOVERNANCE)
         .accounts({
           instituteRegistry: registryPDA,
+          governanceConfig: findGovernanceConfigPda(programs),
           authority: provider.wallet.publicKey,
           systemProgram: PublicKey.default,
         })
@@ -5,13 +5,13 @@ import React, { useState } from 'react';
 import { Search, Shield, CheckCircle, XCircle, AlertTriangle, FileText, Calendar, Building, User, Hash, Upload, Loader2, Download, Eye, Zap, Clock, Database } from 'lucide-react';
 import { PublicKey } from '@solana/web3.js';
 import { useAnchorPrograms } from '@/lib/useAnchorProgram';
+import { verificationAccounts } from '@/lib/accounts';
 import { useBloomFilter } from '@/lib/useBloomFilter';
 import crypto from 'crypto';
 
 const VerificationPortal = () => {
   const programs = useAnchorPrograms();
   const { 
-    bloomFilter, 
     isLoading: isBloomLoading, 
     isSynced: isBloomSynced, 
     syncBloomFilter, 
@@ -141,9 +141,7 @@ const VerificationPortal = () => {
 
       const certificate = await programs.certificateProgram.methods
         .verifyCertificate()
-        .accounts({
-          certificate: certificatePda,
-        })
+        .accounts(await verificationAccounts(programs, certificatePda))
         .view();
 
       const blockchainTime = Date.now() - blockchainStartTime;
@@ -347,7 +345,7 @@ const VerificationPortal = () => {
             <div className="mt-4 inline-flex items-center space-x-2 px-4 py-2 bg-green-50 border border-green-200 rounded-lg">
               <Zap className="w-4 h-4 text-green-600" />
               <span className="text-sm text-green-800">
-                <span className="font-semibold">Optimization Active:</span> {bloomStats.bitsSet.toLocaleString()} certificates indexed
+                <span className="font-semibold">Optimization Active:</span> {bloomStats.insertedCount.toLocaleString()} certificates indexed
               </span>
             </div>
           )}
@@ -908,7 +906,7 @@ const VerificationPortal = () => {
       <footer className="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8 text-center text-sm text-gray-500 mt-12">
         <p>Certificate Verification Portal - Powered by Solana Blockchain + Bloom Filter Optimization</p>
         <p className="mt-2 text-xs text-gray-400">
-          Bloom Filter: 1 MB • 3 Hash Functions • ~1% False Positive Rate • 90% Faster for Non-Existent Certificates
+          Bloom Filter: On-chain CertificateFilter • 1 MiB per Generation • 90% Faster for Non-Existent Certificates
         </p>
       </footer>
     </div>
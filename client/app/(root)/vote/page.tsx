@@ -5,8 +5,18 @@ import React, { useState, useEffect } from 'react';
 import { Vote, Users, UserPlus, CheckCircle, XCircle, Shield, AlertTriangle, Loader2, TrendingUp, Clock, Award } from 'lucide-react';
 import { useAnchorWallet } from '@solana/wallet-adapter-react';
 import { WalletMultiButton } from '@solana/wallet-adapter-react-ui';
-import { PublicKey, SystemProgram } from '@solana/web3.js';
+import { PublicKey } from '@solana/web3.js';
 import { useAnchorPrograms } from '@/lib/useAnchorProgram';
+import {
+  DEFAULT_VOTING_PERIOD,
+  admissionElectionAccounts,
+  admissionVoteAccounts,
+  approvalPercentage,
+  fetchRegisteredInstitutes,
+  findVotingStatePda,
+  latestAdmissionRound,
+  resolveInstitute,
+} from '@/lib/accounts';
 
 const VotingPortal = () => {
   const wallet = useAnchorWallet();
@@ -47,18 +57,17 @@ const VotingPortal = () => {
       );
       
       const registry = await programs.validatorProgram.account.instituteRegistry.fetch(registryPda);
+      const institutes = await fetchRegisteredInstitutes(programs);
       
       setRegistryInfo({
-        authority: registry.authority.toBase58(),
-        institutes: registry.registeredInstitutes,
-        count: registry.registeredInstitutes.length
+        authority: registry.authorities.map(key => key.toBase58()).join(', '),
+        institutes,
+        count: registry.instituteCount
       });
       
-      const registered = registry.registeredInstitutes.some(
-        key => key.toBase58() === wallet.publicKey.toBase58()
-      );
+      const institute = await resolveInstitute(programs, wallet.publicKey);
       
-      setIsRegistered(registered);
+      setIsRegistered(institute !== null);
     } catch (err) {
       console.error('Registry check error:', err);
       setIsRegistered(false);
@@ -81,24 +90,9 @@ const VotingPortal = () => {
 
     setLoading(true);
     try {
-      const [votingStatePda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('voting_state'), candidatePubkey.toBuffer()],
-        programs.validatorProgram.programId
-      );
-
-      const [registryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('institute_registry')],
-        programs.validatorProgram.programId
-      );
-
       await programs.validatorProgram.methods
-        .newInstituteElection(candidatePubkey)
-        .accounts({
-          votingState: votingStatePda,
-          instituteRegistry: registryPda,
-          proposer: wallet.publicKey,
-          systemProgram: SystemProgram.programId,
-        })
+        .newInstituteElection(candidatePubkey, DEFAULT_VOTING_PERIOD)
+        .accounts(await admissionElectionAccounts(programs, candidatePubkey, wallet.publicKey))
         .rpc();
 
       showMessage('success', 'Election created successfully! Institutes can now vote.');
@@ -131,20 +125,22 @@ const VotingPortal = () => {
     try {
       const candidatePubkey = new PublicKey(candidateAddr);
       
-      const [votingStatePda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('voting_state'), candidatePubkey.toBuffer()],
-        programs.validatorProgram.programId
-      );
+      const round = await latestAdmissionRound(programs, candidatePubkey);
+      if (round === null) return null;
 
-      const votingState = await programs.validatorProgram.account.votingState.fetch(votingStatePda);
+      const votingState = await programs.validatorProgram.account.votingState.fetch(
+        findVotingStatePda(programs, candidatePubkey, round)
+      );
       
-      const hasVoted = votingState.votesFor.some(v => v.toBase58() === wallet.publicKey.toBase58()) ||
-                       votingState.votesAgainst.some(v => v.toBase58() === wallet.publicKey.toBase58());
+      const institute = await resolveInstitute(programs, wallet.publicKey);
+      const votedAs = v => institute !== null && v.equals(institute);
+      const hasVoted = votingState.votesFor.some(votedAs) || votingState.votesAgainst.some(votedAs);
       
       return {
-        candidate: votingState.candidateInstitute.toBase58(),
+        candidate: votingState.subject.toBase58(),
         votesFor: votingState.votesFor.length,
         votesAgainst: votingState.votesAgainst.length,
+        approvalBps: approvalPercentage(votingState),
         totalEligible: votingState.totalEligibleVoters,
         status: votingState.status,
         hasVoted,
@@ -187,24 +183,10 @@ const VotingPortal = () => {
     setLoading(true);
     try {
       const candidatePubkey = new PublicKey(candidateAddr);
-      
-      const [votingStatePda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('voting_state'), candidatePubkey.toBuffer()],
-        programs.validatorProgram.programId
-      );
-
-      const [registryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('institute_registry')],
-        programs.validatorProgram.programId
-      );
 
       await programs.validatorProgram.methods
         .vote(voteFor)
-        .accounts({
-          votingState: votingStatePda,
-          instituteRegistry: registryPda,
-          voter: wallet.publicKey,
-        })
+        .accounts(await admissionVoteAccounts(programs, candidatePubkey, wallet.publicKey))
         .rpc();
 
       showMessage('success', `Vote ${voteFor ? 'FOR' : 'AGAINST'} recorded successfully!`);
@@ -218,8 +200,10 @@ const VotingPortal = () => {
       console.error('Vote error:', err);
       if (err.toString().includes('AlreadyVoted')) {
         showMessage('error', 'You have already voted on this candidate');
-      } else if (err.toString().includes('VotingNotActive')) {
+      } else if (err.toString().includes('VotingNotActive') || err.toString().includes('VotingPeriodEnded')) {
         showMessage('error', 'This election is no longer active');
+      } else if (err.toString().includes('VoterNotInElectorate')) {
+        showMessage('error', 'Your institute joined after this election opened and cannot vote on it');
       } else {
         showMessage('error', 'Failed to vote: ' + err.message);
       }
@@ -235,6 +219,8 @@ const VotingPortal = () => {
       return <span className="px-3 py-1 bg-green-100 text-green-700 rounded-full text-xs font-semibold">✅ Approved</span>;
     } else if (status.rejected) {
       return <span className="px-3 py-1 bg-red-100 text-red-700 rounded-full text-xs font-semibold">❌ Rejected</span>;
+    } else if (status.expired) {
+      return <span className="px-3 py-1 bg-gray-100 text-gray-700 rounded-full text-xs font-semibold">⌛ Expired</span>;
     }
     return <span className="px-3 py-1 bg-gray-100 text-gray-700 rounded-full text-xs font-semibold">Unknown</span>;
   };
@@ -468,6 +454,10 @@ const VotingPortal = () => {
                           style={{width: `${(checkedElection.votesFor + checkedElection.votesAgainst) / checkedElection.totalEligible * 100}%`}}
                         />
                       </div>
+                      <div className="flex justify-between text-sm text-gray-600 mt-2">
+                        <span>Approval of Votes Cast</span>
+                        <span>{(checkedElection.approvalBps / 100).toFixed(0)}%</span>
+                      </div>
                     </div>
 
                     {checkedElection.hasVoted && (
@@ -1,10 +1,11 @@
 'use client';
 import React, { useState, useEffect } from 'react';
-import { PublicKey, SystemProgram } from '@solana/web3.js';
+import { PublicKey } from '@solana/web3.js';
 import { useAnchorWallet } from '@solana/wallet-adapter-react';
 import { WalletMultiButton } from '@solana/wallet-adapter-react-ui';
 import { AlertCircle, CheckCircle, Upload, Search, FileText, Shield, Plus, Edit, Loader2 } from 'lucide-react';
 import { useAnchorPrograms } from '@/lib/useAnchorProgram';
+import { issuanceAccounts, resolveInstitute, verificationAccounts } from '@/lib/accounts';
 import crypto from 'crypto';
 
 const CertificateSystemApp = () => {
@@ -41,24 +42,9 @@ const CertificateSystemApp = () => {
 
   const checkRegistration = async () => {
     try {
-      const [instituteRegistryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('institute_registry')],
-        programs.validatorProgram.programId
-      );
-
-      const registry = await programs.validatorProgram.account.instituteRegistry.fetch(
-        instituteRegistryPda
-      );
-
-
-      console.log("registry",registry);
-      
-
-      const registered = registry.registeredInstitutes.some(
-        (key) => key.toBase58() === wallet.publicKey.toBase58()
-      );
+      const institute = await resolveInstitute(programs, wallet.publicKey);
 
-      setIsRegistered(registered);
+      setIsRegistered(institute !== null);
     } catch (err) {
       console.error('Error checking registration:', err);
       setIsRegistered(false);
@@ -86,19 +72,12 @@ const CertificateSystemApp = () => {
         programs.certificateProgram.programId
       );
 
-      const [instituteRegistryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('institute_registry')],
-        programs.validatorProgram.programId
-      );
-
       await programs.certificateProgram.methods
-        .addCertificate(Array.from(certHashArray))
+        .addCertificate(Array.from(certHashArray), null, null)
         .accounts({
+          ...(await issuanceAccounts(programs, wallet.publicKey)),
           certificate: certificatePda,
-          issuer: wallet.publicKey,
-          instituteRegistry: instituteRegistryPda,
-          instituteValidatorProgram: programs.validatorProgram.programId,
-          systemProgram: SystemProgram.programId,
+          credentialSchema: null,
         })
         .rpc();
 
@@ -130,9 +109,7 @@ const CertificateSystemApp = () => {
 
       const status = await programs.certificateProgram.methods
         .verifyCertificate()
-        .accounts({
-          certificate: certificatePda,
-        })
+        .accounts(await verificationAccounts(programs, certificatePda))
         .view();
 
       setVerificationResult(status);
@@ -174,20 +151,12 @@ const CertificateSystemApp = () => {
         programs.certificateProgram.programId
       );
 
-      const [instituteRegistryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('institute_registry')],
-        programs.validatorProgram.programId
-      );
-
       await programs.certificateProgram.methods
         .correctCertificate(Array.from(oldCertHashArray), Array.from(newCertHashArray))
         .accounts({
+          ...(await issuanceAccounts(programs, wallet.publicKey)),
           oldCertificatePda: oldCertPda,
           newCertificate: newCertPda,
-          issuer: wallet.publicKey,
-          instituteRegistry: instituteRegistryPda,
-          instituteValidatorProgram: programs.validatorProgram.programId,
-          systemProgram: SystemProgram.programId,
         })
         .rpc();
 
@@ -6,6 +6,20 @@ import { useAnchorWallet } from '@solana/wallet-adapter-react';
 import { WalletMultiButton } from '@solana/wallet-adapter-react-ui';
 import { AlertCircle, CheckCircle, Upload, Search, FileText, Shield, Plus, Edit, Loader2, Users, Vote, UserPlus } from 'lucide-react';
 import { useAnchorPrograms } from '@/lib/useAnchorProgram';
+import {
+  DEFAULT_GOVERNANCE,
+  DEFAULT_VOTING_PERIOD,
+  admissionElectionAccounts,
+  admissionVoteAccounts,
+  fetchRegisteredInstitutes,
+  findGovernanceConfigPda,
+  findVotingStatePda,
+  initialInstituteAccounts,
+  issuanceAccounts,
+  latestAdmissionRound,
+  resolveInstitute,
+  verificationAccounts,
+} from '@/lib/accounts';
 import crypto from 'crypto';
 
 const CertificateSystemApp = () => {
@@ -55,19 +69,18 @@ const CertificateSystemApp = () => {
       const registry = await programs.validatorProgram.account.instituteRegistry.fetch(
         instituteRegistryPda
       );
+      const institutes = await fetchRegisteredInstitutes(programs);
 
       setRegistryExists(true);
       setRegistryInfo({
-        authority: registry.authority.toBase58(),
-        institutes: registry.registeredInstitutes,
-        count: registry.registeredInstitutes.length
+        authority: registry.authorities.map((key) => key.toBase58()).join(', '),
+        institutes,
+        count: registry.instituteCount
       });
 
-      const registered = registry.registeredInstitutes.some(
-        (key) => key.toBase58() === wallet.publicKey.toBase58()
-      );
+      const institute = await resolveInstitute(programs, wallet.publicKey);
 
-      setIsRegistered(registered);
+      setIsRegistered(institute !== null);
     } catch (err) {
       console.log('Registry not initialized yet');
       setRegistryExists(false);
@@ -100,12 +113,14 @@ const CertificateSystemApp = () => {
       const institutePubkeys = validAddresses.map(addr => new PublicKey(addr));
 
       await programs.validatorProgram.methods
-        .initializeRegistry(institutePubkeys)
+        .initializeRegistry(institutePubkeys, DEFAULT_GOVERNANCE)
         .accounts({
           instituteRegistry: instituteRegistryPda,
+          governanceConfig: findGovernanceConfigPda(programs),
           authority: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(initialInstituteAccounts(programs, institutePubkeys))
         .rpc();
 
       showMessage('success', 'Registry initialized successfully!');
@@ -135,24 +150,9 @@ const CertificateSystemApp = () => {
 
     setLoading(true);
     try {
-      const [votingStatePda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('voting_state'), candidatePubkey.toBuffer()],
-        programs.validatorProgram.programId
-      );
-
-      const [instituteRegistryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('institute_registry')],
-        programs.validatorProgram.programId
-      );
-
       await programs.validatorProgram.methods
-        .newInstituteElection(candidatePubkey)
-        .accounts({
-          votingState: votingStatePda,
-          instituteRegistry: instituteRegistryPda,
-          proposer: wallet.publicKey,
-          systemProgram: SystemProgram.programId,
-        })
+        .newInstituteElection(candidatePubkey, DEFAULT_VOTING_PERIOD)
+        .accounts(await admissionElectionAccounts(programs, candidatePubkey, wallet.publicKey))
         .rpc();
 
       showMessage('success', 'Election created successfully!');
@@ -169,24 +169,10 @@ const CertificateSystemApp = () => {
     setLoading(true);
     try {
       const candidatePubkey = new PublicKey(candidateAddr);
-      
-      const [votingStatePda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('voting_state'), candidatePubkey.toBuffer()],
-        programs.validatorProgram.programId
-      );
-
-      const [instituteRegistryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('institute_registry')],
-        programs.validatorProgram.programId
-      );
 
       await programs.validatorProgram.methods
         .vote(voteFor)
-        .accounts({
-          votingState: votingStatePda,
-          instituteRegistry: instituteRegistryPda,
-          voter: wallet.publicKey,
-        })
+        .accounts(await admissionVoteAccounts(programs, candidatePubkey, wallet.publicKey))
         .rpc();
 
       showMessage('success', `Vote ${voteFor ? 'for' : 'against'} recorded successfully!`);
@@ -205,24 +191,26 @@ const CertificateSystemApp = () => {
     
     try {
       const candidatePubkey = new PublicKey(candidateAddr);
-      
-      const [votingStatePda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('voting_state'), candidatePubkey.toBuffer()],
-        programs.validatorProgram.programId
-      );
+
+      const round = await latestAdmissionRound(programs, candidatePubkey);
+      if (round === null) {
+        setVotingStateInfo(null);
+        return;
+      }
 
       const votingState = await programs.validatorProgram.account.votingState.fetch(
-        votingStatePda
+        findVotingStatePda(programs, candidatePubkey, round)
       );
+      const institute = await resolveInstitute(programs, wallet.publicKey);
+      const votedAs = (v) => institute !== null && v.equals(institute);
 
       setVotingStateInfo({
-        candidate: votingState.candidateInstitute.toBase58(),
+        candidate: votingState.subject.toBase58(),
         votesFor: votingState.votesFor.length,
         votesAgainst: votingState.votesAgainst.length,
         totalEligible: votingState.totalEligibleVoters,
         status: votingState.status,
-        hasVoted: votingState.votesFor.some(v => v.toBase58() === wallet.publicKey.toBase58()) ||
-                  votingState.votesAgainst.some(v => v.toBase58() === wallet.publicKey.toBase58())
+        hasVoted: votingState.votesFor.some(votedAs) || votingState.votesAgainst.some(votedAs)
       });
     } catch (err) {
       console.error('Error fetching voting state:', err);
@@ -251,19 +239,12 @@ const CertificateSystemApp = () => {
         programs.certificateProgram.programId
       );
 
-      const [instituteRegistryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('institute_registry')],
-        programs.validatorProgram.programId
-      );
-
       await programs.certificateProgram.methods
-        .addCertificate(Array.from(certHashArray))
+        .addCertificate(Array.from(certHashArray), null, null)
         .accounts({
+          ...(await issuanceAccounts(programs, wallet.publicKey)),
           certificate: certificatePda,
-          issuer: wallet.publicKey,
-          instituteRegistry: instituteRegistryPda,
-          instituteValidatorProgram: programs.validatorProgram.programId,
-          systemProgram: SystemProgram.programId,
+          credentialSchema: null,
         })
         .rpc();
 
@@ -295,9 +276,7 @@ const CertificateSystemApp = () => {
 
       const status = await programs.certificateProgram.methods
         .verifyCertificate()
-        .accounts({
-          certificate: certificatePda,
-        })
+        .accounts(await verificationAccounts(programs, certificatePda))
         .view();
 
       setVerificationResult(status);
@@ -339,20 +318,12 @@ const CertificateSystemApp = () => {
         programs.certificateProgram.programId
       );
 
-      const [instituteRegistryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('institute_registry')],
-        programs.validatorProgram.programId
-      );
-
       await programs.certificateProgram.methods
         .correctCertificate(Array.from(oldCertHashArray), Array.from(newCertHashArray))
         .accounts({
+          ...(await issuanceAccounts(programs, wallet.publicKey)),
           oldCertificatePda: oldCertPda,
           newCertificate: newCertPda,
-          issuer: wallet.publicKey,
-          instituteRegistry: instituteRegistryPda,
-          instituteValidatorProgram: programs.validatorProgram.programId,
-          systemProgram: SystemProgram.programId,
         })
         .rpc();
 
@@ -718,7 +689,8 @@ const CertificateSystemApp = () => {
                                 <span className="text-gray-600">Status:</span>
                                 <span className="ml-2 font-semibold">
                                   {votingStateInfo.status.active ? '🟢 Active' : 
-                                   votingStateInfo.status.approved ? '✅ Approved' : '❌ Rejected'}
+                                   votingStateInfo.status.approved ? '✅ Approved' :
+                                   votingStateInfo.status.expired ? '⌛ Expired' : '❌ Rejected'}
                                 </span>
                               </div>
                             </div>
@@ -3,8 +3,9 @@ import React, { useState, useMemo } from 'react';
 import { Upload, FileText, Hash, CheckCircle, AlertCircle, Loader2, X, Download, Eye } from 'lucide-react';
 import { useAnchorWallet } from '@solana/wallet-adapter-react';
 import { WalletMultiButton } from '@solana/wallet-adapter-react-ui';
-import { PublicKey, SystemProgram } from '@solana/web3.js';
+import { PublicKey } from '@solana/web3.js';
 import { useAnchorPrograms } from '@/lib/useAnchorProgram';
+import { issuanceAccounts, resolveInstitute } from '@/lib/accounts';
 import crypto from 'crypto';
 import { PDFParse } from 'pdf-parse';
 import axios from 'axios';
@@ -42,15 +43,8 @@ const IssuerPortal = () => {
 
   const checkRegistration = async () => {
     try {
-      const [registryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('institute_registry')],
-        programs.validatorProgram.programId
-      );
-      const registry = await programs.validatorProgram.account.instituteRegistry.fetch(registryPda);
-      const registered = registry.registeredInstitutes.some(
-        key => key.toBase58() === wallet.publicKey.toBase58()
-      );
-      setIsRegistered(registered);
+      const institute = await resolveInstitute(programs, wallet.publicKey);
+      setIsRegistered(institute !== null);
     } catch (err) {
       setIsRegistered(false);
     }
@@ -251,20 +245,13 @@ const IssuerPortal = () => {
         programs.certificateProgram.programId
       );
 
-      const [registryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('institute_registry')],
-        programs.validatorProgram.programId
-      );
-
       // Get the transaction signature
       const signature = await programs.certificateProgram.methods
-        .addCertificate(Array.from(certHashArray))
+        .addCertificate(Array.from(certHashArray), null, null)
         .accounts({
+          ...(await issuanceAccounts(programs, wallet.publicKey)),
           certificate: certificatePda,
-          issuer: wallet.publicKey,
-          instituteRegistry: registryPda,
-          instituteValidatorProgram: programs.validatorProgram.programId,
-          systemProgram: SystemProgram.programId,
+          credentialSchema: null,
         })
         .rpc();
 
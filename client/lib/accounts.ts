@@ -0,0 +1,257 @@
+"use client";
+
+import { BN } from "@coral-xyz/anchor";
+import { AccountMeta, PublicKey, SystemProgram } from "@solana/web3.js";
+import { useAnchorPrograms } from "@/lib/useAnchorProgram";
+
+type Programs = NonNullable<ReturnType<typeof useAnchorPrograms>>;
+
+// Default voting period of admission elections (1 day); the governance config
+// may require a longer one
+export const DEFAULT_VOTING_PERIOD = new BN(24 * 60 * 60);
+
+// Governance a new registry starts with: unanimity of all eligible voters
+export const DEFAULT_GOVERNANCE = {
+  approvalThresholdBps: 10_000,
+  quorumBps: 10_000,
+  minVotingPeriod: new BN(60 * 60),
+  removedIssuerPolicy: { grandfather: {} },
+};
+
+export function findInstituteRegistryPda(programs: Programs): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("institute_registry")],
+    programs.validatorProgram.programId
+  )[0];
+}
+
+export function findGovernanceConfigPda(programs: Programs): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config")],
+    programs.validatorProgram.programId
+  )[0];
+}
+
+export function findInstituteEntryPda(programs: Programs, institute: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("institute"), institute.toBuffer()],
+    programs.validatorProgram.programId
+  )[0];
+}
+
+export function findInstituteProfilePda(programs: Programs, institute: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("institute_profile"), institute.toBuffer()],
+    programs.validatorProgram.programId
+  )[0];
+}
+
+export function findInstituteKeyPda(programs: Programs, key: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("institute_key"), key.toBuffer()],
+    programs.validatorProgram.programId
+  )[0];
+}
+
+// Admission elections on a candidate are numbered in rounds, starting at 0
+export function findElectionHistoryPda(programs: Programs, candidate: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("election_history"), Buffer.from("voting_state"), candidate.toBuffer()],
+    programs.validatorProgram.programId
+  )[0];
+}
+
+export function findVotingStatePda(
+  programs: Programs,
+  candidate: PublicKey,
+  round: number
+): PublicKey {
+  const roundBytes = Buffer.alloc(4);
+  roundBytes.writeUInt32LE(round);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("voting_state"), candidate.toBuffer(), roundBytes],
+    programs.validatorProgram.programId
+  )[0];
+}
+
+export function findCertificatePda(
+  programs: Programs,
+  certificateHash: Uint8Array | number[]
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("certificate"), Buffer.from(certificateHash)],
+    programs.certificateProgram.programId
+  )[0];
+}
+
+// Remaining accounts expected by initialize_registry: the entry, profile and
+// key PDAs of each founding institute, in order
+export function initialInstituteAccounts(
+  programs: Programs,
+  institutes: PublicKey[]
+): AccountMeta[] {
+  return institutes.flatMap((institute) =>
+    [
+      findInstituteEntryPda(programs, institute),
+      findInstituteProfilePda(programs, institute),
+      findInstituteKeyPda(programs, institute),
+    ].map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }))
+  );
+}
+
+// Active institutes, discovered by scanning InstituteEntry accounts
+export async function fetchRegisteredInstitutes(programs: Programs): Promise<PublicKey[]> {
+  const entries = await programs.validatorProgram.account.instituteEntry.all();
+  return entries
+    .filter((entry) => "active" in entry.account.status)
+    .map((entry) => entry.account.institute);
+}
+
+// Institute a signing key (primary key or delegate) acts for, or null when the key
+// is unknown, retired or belongs to a removed institute
+export async function resolveInstitute(
+  programs: Programs,
+  key: PublicKey
+): Promise<PublicKey | null> {
+  const instituteKey = await programs.validatorProgram.account.instituteKey.fetchNullable(
+    findInstituteKeyPda(programs, key)
+  );
+  if (!instituteKey) return null;
+  if (instituteKey.retiredAt && instituteKey.retiredAt.toNumber() * 1000 <= Date.now()) {
+    return null;
+  }
+
+  const entry = await programs.validatorProgram.account.instituteEntry.fetchNullable(
+    findInstituteEntryPda(programs, instituteKey.institute)
+  );
+  return entry && "active" in entry.status ? instituteKey.institute : null;
+}
+
+// Round of the latest admission election on a candidate, or null if none was started
+export async function latestAdmissionRound(
+  programs: Programs,
+  candidate: PublicKey
+): Promise<number | null> {
+  const history = await programs.validatorProgram.account.electionHistory.fetchNullable(
+    findElectionHistoryPda(programs, candidate)
+  );
+  return history && history.rounds > 0 ? history.rounds - 1 : null;
+}
+
+// Mirrors VotingState::approval_percentage: share of the votes cast that are in
+// favour, in basis points (10_000 = 100%)
+export function approvalPercentage(votingState: {
+  votesFor: PublicKey[];
+  votesAgainst: PublicKey[];
+}): number {
+  const totalVotes = votingState.votesFor.length + votingState.votesAgainst.length;
+  if (totalVotes === 0) return 0;
+  return Math.floor((votingState.votesFor.length * 10_000) / totalVotes);
+}
+
+// Accounts of new_institute_election for the next round on the candidate
+export async function admissionElectionAccounts(
+  programs: Programs,
+  candidate: PublicKey,
+  proposer: PublicKey
+) {
+  const latestRound = await latestAdmissionRound(programs, candidate);
+  return {
+    instituteRegistry: findInstituteRegistryPda(programs),
+    electionHistory: findElectionHistoryPda(programs, candidate),
+    votingState: findVotingStatePda(programs, candidate, latestRound === null ? 0 : latestRound + 1),
+    governanceConfig: findGovernanceConfigPda(programs),
+    candidateEntry: findInstituteEntryPda(programs, candidate),
+    candidateKey: findInstituteKeyPda(programs, candidate),
+    proposer,
+    systemProgram: SystemProgram.programId,
+  };
+}
+
+// Accounts of vote on the latest admission election on the candidate; the subject
+// accounts are written when the vote concludes the election
+export async function admissionVoteAccounts(
+  programs: Programs,
+  candidate: PublicKey,
+  voter: PublicKey
+) {
+  const round = await latestAdmissionRound(programs, candidate);
+  if (round === null) throw new Error("No election found for this candidate");
+
+  const institute = await resolveInstitute(programs, voter);
+  if (!institute) throw new Error("Your wallet is not a registered institute");
+
+  return {
+    votingState: findVotingStatePda(programs, candidate, round),
+    electionHistory: findElectionHistoryPda(programs, candidate),
+    instituteRegistry: findInstituteRegistryPda(programs),
+    governanceConfig: findGovernanceConfigPda(programs),
+    voterKey: findInstituteKeyPda(programs, voter),
+    voterEntry: findInstituteEntryPda(programs, institute),
+    subjectEntry: findInstituteEntryPda(programs, candidate),
+    subjectProfile: findInstituteProfilePda(programs, candidate),
+    subjectKey: findInstituteKeyPda(programs, candidate),
+    voter,
+    systemProgram: SystemProgram.programId,
+  };
+}
+
+// Accounts shared by add_certificate and correct_certificate: the issuer's key and
+// entry, the IssuerStats and next CertificateIndex of its institute, and the
+// current CertificateFilter (none until the filter is initialized)
+export async function issuanceAccounts(programs: Programs, issuer: PublicKey) {
+  const institute = await resolveInstitute(programs, issuer);
+  if (!institute) throw new Error("Your wallet is not a registered institute");
+
+  const certificateProgramId = programs.certificateProgram.programId;
+  const [issuerStats] = PublicKey.findProgramAddressSync(
+    [Buffer.from("issuer_stats"), institute.toBuffer()],
+    certificateProgramId
+  );
+  const stats = await programs.certificateProgram.account.issuerStats.fetchNullable(issuerStats);
+  const nextSerial: BN = stats ? stats.nextSerial : new BN(0);
+  const [certificateIndex] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("cert_by_issuer"),
+      institute.toBuffer(),
+      nextSerial.toArrayLike(Buffer, "le", 8),
+    ],
+    certificateProgramId
+  );
+
+  const [filterState] = PublicKey.findProgramAddressSync(
+    [Buffer.from("certificate_filter_state")],
+    certificateProgramId
+  );
+  const state = await programs.certificateProgram.account.certificateFilterState.fetchNullable(
+    filterState
+  );
+
+  return {
+    issuer,
+    instituteValidatorProgram: programs.validatorProgram.programId,
+    instituteRegistry: findInstituteRegistryPda(programs),
+    issuerKey: findInstituteKeyPda(programs, issuer),
+    issuerEntry: findInstituteEntryPda(programs, institute),
+    issuerStats,
+    certificateIndex,
+    filterState,
+    certificateFilter: state ? state.current : null,
+    systemProgram: SystemProgram.programId,
+  };
+}
+
+// Accounts of verify_certificate; the issuer's registry entry is looked up from the
+// certificate itself
+export async function verificationAccounts(programs: Programs, certificate: PublicKey) {
+  const account = await programs.certificateProgram.account.certificate.fetch(certificate);
+  return {
+    certificate,
+    instituteValidatorProgram: programs.validatorProgram.programId,
+    instituteRegistry: findInstituteRegistryPda(programs),
+    issuerEntry: findInstituteEntryPda(programs, account.issuer),
+    governanceConfig: findGovernanceConfigPda(programs),
+    issuerProfile: null,
+    credentialSchema: account.schema,
+  };
+}
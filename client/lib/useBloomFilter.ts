@@ -1,35 +1,66 @@
-
 import { useEffect, useState } from 'react';
-import { Program } from '@coral-xyz/anchor';
-import BloomFilterManager, { BloomFilter } from '@/actions/bloomFilter';
+import { BN, Program } from '@coral-xyz/anchor';
+import { PublicKey } from '@solana/web3.js';
 import crypto from 'crypto';
 
+// Offsets into the raw CertificateFilter account: 8-byte discriminator, then the
+// header fields, then the filter bits
+const NUM_BITS_OFFSET = 16;
+const NUM_HASHES_OFFSET = 24;
+const INSERTED_COUNT_OFFSET = 32;
+const BITS_SET_OFFSET = 40;
+const PREVIOUS_OFFSET = 56;
+const BITS_OFFSET = 120;
+
+interface FilterGeneration {
+  address: PublicKey;
+  data: Buffer;
+  numBits: BN;
+  numHashes: number;
+}
+
 interface UseBloomFilterReturn {
-  bloomFilter: BloomFilter;
+  generations: FilterGeneration[];
   isLoading: boolean;
   isSynced: boolean;
   syncBloomFilter: () => Promise<void>;
   checkCertificate: (certData: string) => boolean;
-  addCertificate: (certData: string) => void;
   stats: {
-    size: number;
+    generations: number;
     numHashFunctions: number;
+    insertedCount: number;
     bitsSet: number;
     fillRate: number;
   } | null;
 }
 
+// Mirrors CertificateFilter::contains: bit positions by double hashing the first two
+// little-endian words of the certificate hash, with the generation's FilterParams
+function generationContains(generation: FilterGeneration, certificateHash: Buffer): boolean {
+  const h1 = new BN(certificateHash.subarray(0, 8), 'le');
+  const h2 = new BN(certificateHash.subarray(8, 16), 'le').setn(0, true);
+  for (let i = 0; i < generation.numHashes; i++) {
+    const position = h1.add(h2.muln(i)).maskn(64).umod(generation.numBits).toNumber();
+    const byte = generation.data[BITS_OFFSET + Math.floor(position / 8)];
+    if ((byte & (1 << position % 8)) === 0) {
+      return false;
+    }
+  }
+  return true;
+}
+
 export function useBloomFilter(
   certificateProgram: Program | null
 ): UseBloomFilterReturn {
-  const [bloomFilter] = useState(() => BloomFilterManager.getInstance());
+  const [generations, setGenerations] = useState<FilterGeneration[]>([]);
   const [isLoading, setIsLoading] = useState(false);
   const [isSynced, setIsSynced] = useState(false);
-  const [stats, setStats] = useState(bloomFilter.getStats());
+  const [stats, setStats] = useState<UseBloomFilterReturn['stats']>(null);
 
   /**
-   * Sync Bloom Filter with blockchain certificates
-   * This fetches all certificates and populates the Bloom Filter
+   * Load the on-chain CertificateFilter generations, from the current one back to the first
+   * Issuance inserts every certificate hash into the current generation, so there is
+   * nothing to rebuild from the certificate accounts
    */
   const syncBloomFilter = async () => {
     if (!certificateProgram) {
@@ -39,31 +70,58 @@ export function useBloomFilter(
 
     setIsLoading(true);
     try {
-      console.log('Syncing Bloom Filter with blockchain...');
-      
-      // Clear existing filter
-      bloomFilter.clear();
+      const [filterState] = PublicKey.findProgramAddressSync(
+        [Buffer.from('certificate_filter_state')],
+        certificateProgram.programId
+      );
+      const state = await certificateProgram.account.certificateFilterState.fetchNullable(
+        filterState
+      );
+      if (!state) {
+        console.warn('Certificate filter is not initialized');
+        setGenerations([]);
+        setIsSynced(false);
+        return;
+      }
+
+      const loaded: FilterGeneration[] = [];
+      let address: PublicKey = state.current;
+      while (!address.equals(PublicKey.default)) {
+        const account = await certificateProgram.provider.connection.getAccountInfo(address);
+        if (!account) break;
+        const data = account.data;
+        loaded.push({
+          address,
+          data,
+          numBits: new BN(data.subarray(NUM_BITS_OFFSET, NUM_BITS_OFFSET + 8), 'le'),
+          numHashes: data.readUInt32LE(NUM_HASHES_OFFSET),
+        });
+        address = new PublicKey(data.subarray(PREVIOUS_OFFSET, PREVIOUS_OFFSET + 32));
+      }
 
-      // Fetch all certificate accounts from the blockchain
-      const certificates = await certificateProgram.account.certificate.all();
-      
-      console.log(`Found ${certificates.length} certificates`);
+      const insertedCount = loaded.reduce(
+        (sum, g) => sum + Number(g.data.readBigUInt64LE(INSERTED_COUNT_OFFSET)),
+        0
+      );
+      const bitsSet = loaded.reduce(
+        (sum, g) => sum + Number(g.data.readBigUInt64LE(BITS_SET_OFFSET)),
+        0
+      );
+      const totalBits = loaded.reduce((sum, g) => sum + g.numBits.toNumber(), 0);
 
-      // Add each certificate hash to the Bloom Filter
-      certificates.forEach((cert) => {
-        const certHash = cert.account.certificateHash;
-        bloomFilter.add(new Uint8Array(certHash));
+      setGenerations(loaded);
+      setStats({
+        generations: loaded.length,
+        numHashFunctions: loaded.length > 0 ? loaded[0].numHashes : 0,
+        insertedCount,
+        bitsSet,
+        fillRate: totalBits > 0 ? bitsSet / totalBits : 0,
       });
+      setIsSynced(loaded.length > 0);
 
-      // Save to localStorage
-      bloomFilter.save();
-      
-      setIsSynced(true);
-      setStats(bloomFilter.getStats());
-      
-      console.log('Bloom Filter synced successfully', bloomFilter.getStats());
+      console.log(`Loaded ${loaded.length} certificate filter generation(s)`);
     } catch (error) {
-      console.error('Failed to sync Bloom Filter:', error);
+      console.error('Failed to load the certificate filter:', error);
       setIsSynced(false);
     } finally {
       setIsLoading(false);
@@ -71,57 +129,29 @@ export function useBloomFilter(
   };
 
   /**
-   * Check if a certificate might exist (using Bloom Filter first)
-   * Returns true if certificate might exist, false if definitely doesn't exist
+   * Check if a certificate might exist
+   * Returns false only if the hash is absent from every filter generation
    */
   const checkCertificate = (certData: string): boolean => {
-    const hash = crypto.createHash('sha256').update(certData).digest();
-    const hashArray = new Uint8Array(hash);
-    return bloomFilter.contains(hashArray);
-  };
+    // Without a filter nothing can be ruled out
+    if (generations.length === 0) return true;
 
-  /**
-   * Add a certificate to the Bloom Filter
-   * Called after successfully issuing a certificate on-chain
-   */
-  const addCertificate = (certData: string) => {
     const hash = crypto.createHash('sha256').update(certData).digest();
-    const hashArray = new Uint8Array(hash);
-    bloomFilter.add(hashArray);
-    bloomFilter.save();
-    setStats(bloomFilter.getStats());
+    return generations.some((generation) => generationContains(generation, hash));
   };
 
-  // Auto-sync on mount if not synced recently
   useEffect(() => {
-    const checkSync = () => {
-      const lastSync = localStorage.getItem('bloomFilterTimestamp');
-      if (!lastSync) {
-        return false;
-      }
-
-      const lastSyncTime = parseInt(lastSync, 10);
-      const hoursSinceSync = (Date.now() - lastSyncTime) / (1000 * 60 * 60);
-      
-      // Re-sync if more than 24 hours old
-      return hoursSinceSync < 24;
-    };
-
-    if (certificateProgram && !checkSync()) {
+    if (certificateProgram) {
       syncBloomFilter();
-    } else if (certificateProgram) {
-      setIsSynced(true);
-      setStats(bloomFilter.getStats());
     }
   }, [certificateProgram]);
 
   return {
-    bloomFilter,
+    generations,
     isLoading,
     isSynced,
     syncBloomFilter,
     checkCertificate,
-    addCertificate,
     stats,
   };
-}
\ No newline at end of file
+}